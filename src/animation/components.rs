@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimationMode {
+    Loop,
+    Once,
+}
+
+/// A run of `frames` consecutive cells in a horizontal sprite sheet, starting at index 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnimationClip {
+    pub frames: usize,
+    pub fps: f32,
+    pub mode: AnimationMode,
+}
+
+#[derive(Component)]
+pub struct Animation {
+    pub clip: AnimationClip,
+    pub frame: usize,
+    pub timer: Timer,
+    pub finished: bool,
+}
+
+impl Animation {
+    pub fn new(clip: AnimationClip) -> Self {
+        Self {
+            clip,
+            frame: 0,
+            timer: Timer::from_seconds(1.0 / clip.fps, TimerMode::Repeating),
+            finished: false,
+        }
+    }
+
+    pub fn starting_at(mut self, frame: usize) -> Self {
+        self.frame = frame % self.clip.frames;
+        self
+    }
+
+    /// Switches to another clip, keeping the current frame so looping clips blend smoothly.
+    pub fn play(&mut self, clip: AnimationClip) {
+        if self.clip == clip {
+            return;
+        }
+        self.clip = clip;
+        self.frame %= clip.frames;
+        self.timer = Timer::from_seconds(1.0 / clip.fps, TimerMode::Repeating);
+        self.finished = false;
+    }
+}
+
+#[derive(Component)]
+pub struct DespawnOnFinish {}
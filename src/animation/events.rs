@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+pub struct AnimationFinished {
+    pub entity: Entity,
+}
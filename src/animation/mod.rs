@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+mod systems;
+
+use events::*;
+use systems::*;
+
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AnimationFinished>()
+            .add_system(animate)
+            .add_system(despawn_finished_animations.after(animate));
+    }
+}
@@ -0,0 +1,46 @@
+use super::{components::*, events::*};
+use crate::game_time::resources::GameTime;
+use bevy::prelude::*;
+
+pub fn animate(
+    mut query: Query<(Entity, &mut Animation, &mut TextureAtlasSprite)>,
+    mut animation_finished_event_writer: EventWriter<AnimationFinished>,
+    game_time: Res<GameTime>,
+) {
+    for (entity, mut animation, mut sprite) in query.iter_mut() {
+        if !animation.finished {
+            let steps = animation
+                .timer
+                .tick(game_time.delta())
+                .times_finished_this_tick() as usize;
+            let frames = animation.clip.frames;
+            match animation.clip.mode {
+                AnimationMode::Loop => animation.frame = (animation.frame + steps) % frames,
+                AnimationMode::Once if animation.frame + steps < frames => {
+                    animation.frame += steps;
+                }
+                AnimationMode::Once => {
+                    animation.frame = frames - 1;
+                    animation.finished = true;
+                    animation_finished_event_writer.send(AnimationFinished { entity });
+                }
+            }
+        }
+
+        if sprite.index != animation.frame {
+            sprite.index = animation.frame;
+        }
+    }
+}
+
+pub fn despawn_finished_animations(
+    mut commands: Commands,
+    mut animation_finished_event_reader: EventReader<AnimationFinished>,
+    query: Query<(), With<DespawnOnFinish>>,
+) {
+    for event in animation_finished_event_reader.iter() {
+        if query.contains(event.entity) {
+            commands.entity(event.entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct ArenaBackground {}
+
+/// Copy of a sprite drawn on the opposite side of a wrapping arena while it crosses an edge.
+#[derive(Component)]
+pub struct Ghost {
+    pub axes: Vec2,
+}
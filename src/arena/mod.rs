@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+pub const ARENA_WIDTH: f32 = 1280.0;
+pub const ARENA_HEIGHT: f32 = 720.0;
+pub const VIEW_WIDTH: f32 = 1280.0;
+pub const VIEW_HEIGHT: f32 = 720.0;
+pub const ARENA_COLOR: Color = Color::rgb(0.4, 0.4, 0.4);
+pub const GHOST_MARGIN: f32 = 64.0;
+
+pub struct ArenaPlugin;
+
+impl Plugin for ArenaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Arena>()
+            .add_startup_system(spawn_arena_background)
+            .add_system(fit_camera_to_arena)
+            .add_system(resize_arena_background)
+            .add_system(spawn_ghosts::<Sprite, Handle<Image>>)
+            .add_system(spawn_ghosts::<TextureAtlasSprite, Handle<TextureAtlas>>)
+            .add_system(
+                update_ghosts::<Sprite, Handle<Image>>.after(spawn_ghosts::<Sprite, Handle<Image>>),
+            )
+            .add_system(
+                update_ghosts::<TextureAtlasSprite, Handle<TextureAtlas>>
+                    .after(spawn_ghosts::<TextureAtlasSprite, Handle<TextureAtlas>>),
+            );
+    }
+}
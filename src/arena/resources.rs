@@ -0,0 +1,113 @@
+use super::{ARENA_HEIGHT, ARENA_WIDTH, VIEW_HEIGHT, VIEW_WIDTH};
+use crate::settings::resources::Settings;
+use bevy::prelude::*;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EdgeMode {
+    #[default]
+    Bounded,
+    Wrap,
+}
+
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct Arena {
+    pub width: f32,
+    pub height: f32,
+    pub view_width: f32,
+    pub view_height: f32,
+    pub edges: EdgeMode,
+}
+
+impl FromWorld for Arena {
+    fn from_world(world: &mut World) -> Self {
+        match world.get_resource::<Settings>() {
+            Some(settings) => Self {
+                edges: settings.world.edges,
+                ..Self::new(settings.world.width, settings.world.height)
+            },
+            None => Self::new(ARENA_WIDTH, ARENA_HEIGHT),
+        }
+    }
+}
+
+impl Arena {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            view_width: VIEW_WIDTH.min(width),
+            view_height: VIEW_HEIGHT.min(height),
+            edges: EdgeMode::Bounded,
+        }
+    }
+
+    pub fn wraps(&self) -> bool {
+        self.edges == EdgeMode::Wrap
+    }
+
+    pub fn view_size(&self) -> Vec2 {
+        Vec2::new(self.view_width, self.view_height)
+    }
+
+    pub fn is_scrolling(&self) -> bool {
+        self.view_width < self.width || self.view_height < self.height
+    }
+
+    /// Keeps the camera centre far enough from the edges that the view stays inside the world.
+    pub fn clamp_camera(&self, position: Vec2) -> Vec2 {
+        let half_view = self.view_size() * 0.5;
+        position.clamp(half_view, self.size() - half_view)
+    }
+
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.width, self.height)
+    }
+
+    pub fn center(&self) -> Vec2 {
+        self.size() * 0.5
+    }
+
+    /// Smallest and largest positions an object of the given half size can occupy.
+    pub fn bounds(&self, half_size: f32) -> (Vec2, Vec2) {
+        (Vec2::splat(half_size), self.size() - Vec2::splat(half_size))
+    }
+
+    pub fn clamp(&self, translation: Vec3, half_size: f32) -> Vec3 {
+        let (min, max) = self.bounds(half_size);
+        translation.truncate().clamp(min, max).extend(translation.z)
+    }
+
+    /// Keeps an object inside the arena, either by clamping or by wrapping to the opposite edge.
+    pub fn confine(&self, translation: Vec3, half_size: f32) -> Vec3 {
+        match self.edges {
+            EdgeMode::Bounded => self.clamp(translation, half_size),
+            EdgeMode::Wrap => Vec3::new(
+                translation.x.rem_euclid(self.width),
+                translation.y.rem_euclid(self.height),
+                translation.z,
+            ),
+        }
+    }
+
+    /// Shortest offset from `from` to `to`, crossing the seams when the arena wraps.
+    pub fn delta(&self, from: Vec3, to: Vec3) -> Vec2 {
+        let delta = (to - from).truncate();
+        match self.edges {
+            EdgeMode::Bounded => delta,
+            EdgeMode::Wrap => {
+                let size = self.size();
+                delta - size * (delta / size).round()
+            }
+        }
+    }
+
+    pub fn distance(&self, from: Vec3, to: Vec3) -> f32 {
+        self.delta(from, to).length()
+    }
+
+    pub fn random_position(&self) -> Vec2 {
+        Vec2::new(random::<f32>() * self.width, random::<f32>() * self.height)
+    }
+}
@@ -0,0 +1,138 @@
+use super::{components::*, resources::*, ARENA_COLOR, GHOST_MARGIN};
+use crate::{
+    enemy::components::Enemy, game::components::MainCamera, player::components::Player,
+    star::components::Star,
+};
+use bevy::{prelude::*, render::camera::ScalingMode};
+
+pub fn spawn_arena_background(mut commands: Commands, arena: Res<Arena>) {
+    commands.spawn((
+        ArenaBackground {},
+        SpriteBundle {
+            sprite: Sprite {
+                color: ARENA_COLOR,
+                custom_size: Some(arena.size()),
+                ..default()
+            },
+            transform: Transform::from_translation(arena.center().extend(-10.0)),
+            ..default()
+        },
+    ));
+}
+
+pub fn fit_camera_to_arena(
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<MainCamera>>,
+    arena: Res<Arena>,
+) {
+    if !arena.is_changed() {
+        return;
+    }
+
+    for (mut transform, mut projection) in camera_query.iter_mut() {
+        let position = arena.clamp_camera(transform.translation.truncate());
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        projection.scaling_mode = ScalingMode::AutoMin {
+            min_width: arena.view_width,
+            min_height: arena.view_height,
+        };
+    }
+}
+
+pub fn resize_arena_background(
+    mut background_query: Query<(&mut Transform, &mut Sprite), With<ArenaBackground>>,
+    arena: Res<Arena>,
+) {
+    if !arena.is_changed() {
+        return;
+    }
+
+    for (mut transform, mut sprite) in background_query.iter_mut() {
+        let center = arena.center();
+        transform.translation.x = center.x;
+        transform.translation.y = center.y;
+        sprite.custom_size = Some(arena.size());
+    }
+}
+
+/// Generic over the sprite and texture components so that plain sprites and sprite sheets
+/// both get ghosts.
+#[allow(clippy::type_complexity)]
+pub fn spawn_ghosts<S: Component + Clone, H: Component + Clone>(
+    mut commands: Commands,
+    query: Query<(Entity, &H, &S), Or<(Added<Player>, Added<Enemy>, Added<Star>)>>,
+    arena: Res<Arena>,
+) {
+    if !arena.wraps() {
+        return;
+    }
+
+    for (entity, texture, sprite) in query.iter() {
+        commands.entity(entity).with_children(|parent| {
+            for axes in [Vec2::X, Vec2::Y, Vec2::ONE] {
+                parent.spawn((
+                    Ghost { axes },
+                    sprite.clone(),
+                    texture.clone(),
+                    SpatialBundle {
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    },
+                ));
+            }
+        });
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_ghosts<S: Component + Clone, H: Component + Clone + PartialEq>(
+    mut ghost_query: Query<(
+        &Ghost,
+        &Parent,
+        &mut Transform,
+        &mut Visibility,
+        &mut S,
+        &mut H,
+    )>,
+    parent_query: Query<(&Transform, &S, &H), Without<Ghost>>,
+    arena: Res<Arena>,
+) {
+    let side = |value: f32, size: f32| {
+        if value < GHOST_MARGIN {
+            1.0
+        } else if value > size - GHOST_MARGIN {
+            -1.0
+        } else {
+            0.0
+        }
+    };
+
+    for (ghost, parent, mut transform, mut visibility, mut sprite, mut texture) in
+        ghost_query.iter_mut()
+    {
+        let Ok((parent_transform, parent_sprite, parent_texture)) = parent_query.get(parent.get())
+        else {
+            continue;
+        };
+
+        let position = parent_transform.translation;
+        let sides = Vec2::new(
+            side(position.x, arena.width),
+            side(position.y, arena.height),
+        );
+
+        let visible =
+            (ghost.axes.x == 0.0 || sides.x != 0.0) && (ghost.axes.y == 0.0 || sides.y != 0.0);
+        if !visible {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        *visibility = Visibility::Inherited;
+        transform.translation = (ghost.axes * sides * arena.size()).extend(0.0);
+        *sprite = parent_sprite.clone();
+        if *texture != *parent_texture {
+            *texture = parent_texture.clone();
+        }
+    }
+}
@@ -1,18 +1,58 @@
 use bevy::prelude::*;
 
+use ecs::animation::AnimationPlugin;
+use ecs::arena::ArenaPlugin;
+use ecs::camera::CameraPlugin;
+use ecs::camera_effects::CameraEffectsPlugin;
+use ecs::combo::ComboPlugin;
+use ecs::editor::EditorPlugin;
 use ecs::enemy::EnemyPlugin;
 use ecs::game::GamePlugin;
+use ecs::game_time::GameTimePlugin;
+use ecs::level::LevelPlugin;
+use ecs::loading::LoadingPlugin;
+use ecs::menu::MenuPlugin;
+use ecs::minimap::MinimapPlugin;
+use ecs::music::MusicPlugin;
+use ecs::net::NetPlugin;
+use ecs::obstacle::ObstaclePlugin;
+use ecs::particle::ParticlePlugin;
 use ecs::player::PlayerPlugin;
+use ecs::powerup::PowerUpPlugin;
+use ecs::projectile::ProjectilePlugin;
 use ecs::score::ScorePlugin;
+use ecs::settings::SettingsPlugin;
+use ecs::sound::SoundPlugin;
 use ecs::star::StarPlugin;
+use ecs::starfield::StarfieldPlugin;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
+        .add_plugin(SettingsPlugin)
+        .add_plugin(ArenaPlugin)
         .add_plugin(GamePlugin)
+        .add_plugin(GameTimePlugin)
+        .add_plugin(CameraPlugin)
+        .add_plugin(CameraEffectsPlugin)
+        .add_plugin(LevelPlugin)
+        .add_plugin(ObstaclePlugin)
+        .add_plugin(LoadingPlugin)
+        .add_plugin(EditorPlugin)
+        .add_plugin(MenuPlugin)
+        .add_plugin(MinimapPlugin)
+        .add_plugin(MusicPlugin)
+        .add_plugin(NetPlugin)
+        .add_plugin(ParticlePlugin)
+        .add_plugin(AnimationPlugin)
+        .add_plugin(ComboPlugin)
         .add_plugin(EnemyPlugin)
         .add_plugin(PlayerPlugin)
+        .add_plugin(PowerUpPlugin)
+        .add_plugin(ProjectilePlugin)
         .add_plugin(ScorePlugin)
+        .add_plugin(SoundPlugin)
         .add_plugin(StarPlugin)
+        .add_plugin(StarfieldPlugin)
         .run();
 }
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+mod systems;
+
+use crate::game::states::AppState;
+use systems::*;
+
+pub const CAMERA_DEAD_ZONE_WIDTH: f32 = 320.0;
+pub const CAMERA_DEAD_ZONE_HEIGHT: f32 = 180.0;
+pub const CAMERA_FOLLOW_SPEED: f32 = 5.0;
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(follow_player.in_set(OnUpdate(AppState::Game)))
+            .add_system(follow_player.in_set(OnUpdate(AppState::Online)));
+    }
+}
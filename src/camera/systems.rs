@@ -0,0 +1,39 @@
+use super::{CAMERA_DEAD_ZONE_HEIGHT, CAMERA_DEAD_ZONE_WIDTH, CAMERA_FOLLOW_SPEED};
+use crate::{arena::resources::Arena, game::components::MainCamera, player::components::Player};
+use bevy::prelude::*;
+
+pub fn follow_player(
+    mut camera_query: Query<&mut Transform, (With<MainCamera>, Without<Player>)>,
+    player_query: Query<&Transform, With<Player>>,
+    arena: Res<Arena>,
+    time: Res<Time>,
+) {
+    let Ok(mut camera_transform) = camera_query.get_single_mut() else {
+        return;
+    };
+    let mut players = player_query.iter();
+    let Some(first) = players.next() else {
+        return;
+    };
+
+    // Follow the middle of the group, measured through the seams if the arena wraps.
+    let (sum, count) = players.fold((Vec2::ZERO, 1.0), |(sum, count), transform| {
+        (
+            sum + arena.delta(first.translation, transform.translation),
+            count + 1.0,
+        )
+    });
+    let focus = first.translation.truncate() + sum / count;
+
+    let camera = camera_transform.translation.truncate();
+    let offset = focus - camera;
+    let half_dead_zone = Vec2::new(CAMERA_DEAD_ZONE_WIDTH, CAMERA_DEAD_ZONE_HEIGHT) * 0.5;
+    let excess = offset - offset.clamp(-half_dead_zone, half_dead_zone);
+
+    let target = arena.clamp_camera(camera + excess);
+    let blend = 1.0 - (-CAMERA_FOLLOW_SPEED * time.delta_seconds()).exp();
+    let position = arena.clamp_camera(camera.lerp(target, blend));
+
+    camera_transform.translation.x = position.x;
+    camera_transform.translation.y = position.y;
+}
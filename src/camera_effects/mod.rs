@@ -0,0 +1,36 @@
+use bevy::{prelude::*, transform::TransformSystem};
+
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+pub const MAX_SHAKE_OFFSET: f32 = 24.0;
+pub const MAX_SHAKE_ANGLE: f32 = 0.05;
+pub const SHAKE_FREQUENCY: f32 = 25.0;
+pub const TRAUMA_DECAY: f32 = 1.2;
+pub const GAME_OVER_TRAUMA: f32 = 0.9;
+pub const HIT_TRAUMA: f32 = 0.4;
+pub const HIT_STOP_DURATION: f32 = 0.08;
+
+/// Offsets the camera for the frame, after everything else has moved it.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CameraShakeSet;
+
+pub struct CameraEffectsPlugin;
+
+impl Plugin for CameraEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraShake>()
+            .init_resource::<HitStop>()
+            .add_system(remove_camera_shake.in_base_set(CoreSet::PreUpdate))
+            .add_systems((add_trauma, start_hit_stop, update_hit_stop))
+            .add_system(
+                apply_camera_shake
+                    .in_set(CameraShakeSet)
+                    .in_base_set(CoreSet::PostUpdate)
+                    .before(TransformSystem::TransformPropagate),
+            );
+    }
+}
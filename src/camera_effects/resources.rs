@@ -0,0 +1,30 @@
+use super::HIT_STOP_DURATION;
+use bevy::prelude::*;
+
+/// Trauma in `0..=1` decays over time, and the camera shakes by its square so small
+/// knocks stay subtle while big ones are violent.
+#[derive(Resource, Default)]
+pub struct CameraShake {
+    pub trauma: f32,
+    pub offset: Vec2,
+    pub elapsed: f32,
+}
+
+impl CameraShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.0);
+    }
+}
+
+#[derive(Resource)]
+pub struct HitStop {
+    pub timer: Timer,
+}
+
+impl Default for HitStop {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(HIT_STOP_DURATION, TimerMode::Once);
+        timer.pause();
+        Self { timer }
+    }
+}
@@ -0,0 +1,97 @@
+use super::{resources::*, GAME_OVER_TRAUMA, HIT_TRAUMA, MAX_SHAKE_ANGLE, MAX_SHAKE_OFFSET};
+use super::{SHAKE_FREQUENCY, TRAUMA_DECAY};
+use crate::{
+    game::{components::MainCamera, events::GameOver},
+    game_time::resources::{GameTime, TimeModifier},
+    player::events::PlayerHit,
+    settings::resources::Settings,
+};
+use bevy::prelude::*;
+
+/// Cheap smooth noise in `-1..=1` from a few incommensurate sine waves.
+fn noise(t: f32, seed: f32) -> f32 {
+    ((t * 1.0 + seed).sin() + (t * 2.3 + seed * 1.7).sin() + (t * 4.1 + seed * 2.9).sin()) / 3.0
+}
+
+pub fn remove_camera_shake(
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    mut shake: ResMut<CameraShake>,
+) {
+    if shake.offset == Vec2::ZERO {
+        return;
+    }
+
+    for mut transform in camera_query.iter_mut() {
+        transform.translation -= shake.offset.extend(0.0);
+        transform.rotation = Quat::IDENTITY;
+    }
+    shake.offset = Vec2::ZERO;
+}
+
+pub fn add_trauma(
+    mut game_over_event_reader: EventReader<GameOver>,
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut shake: ResMut<CameraShake>,
+) {
+    for _ in game_over_event_reader.iter() {
+        shake.add_trauma(GAME_OVER_TRAUMA);
+    }
+    for _ in player_hit_event_reader.iter() {
+        shake.add_trauma(HIT_TRAUMA);
+    }
+}
+
+pub fn start_hit_stop(
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut hit_stop: ResMut<HitStop>,
+    mut game_time: ResMut<GameTime>,
+    settings: Res<Settings>,
+) {
+    if player_hit_event_reader.iter().count() == 0 || !settings.effects.hit_stop_enabled() {
+        return;
+    }
+
+    hit_stop.timer.reset();
+    hit_stop.timer.unpause();
+    game_time.set_modifier(TimeModifier::HitStop, 0.0);
+}
+
+pub fn update_hit_stop(
+    mut hit_stop: ResMut<HitStop>,
+    mut game_time: ResMut<GameTime>,
+    time: Res<Time>,
+) {
+    if hit_stop.timer.paused() {
+        return;
+    }
+
+    if hit_stop.timer.tick(time.delta()).finished() {
+        hit_stop.timer.pause();
+        game_time.clear_modifier(TimeModifier::HitStop);
+    }
+}
+
+pub fn apply_camera_shake(
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    mut shake: ResMut<CameraShake>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+) {
+    shake.trauma = (shake.trauma - TRAUMA_DECAY * time.delta_seconds()).max(0.0);
+    shake.elapsed += time.delta_seconds();
+
+    let intensity = settings.effects.shake_intensity() * shake.trauma.powi(2);
+    if intensity <= 0.0 {
+        return;
+    }
+
+    let t = shake.elapsed * SHAKE_FREQUENCY;
+    let offset = Vec2::new(noise(t, 0.0), noise(t, 10.0)) * MAX_SHAKE_OFFSET * intensity;
+    let angle = noise(t, 20.0) * MAX_SHAKE_ANGLE * intensity;
+
+    for mut transform in camera_query.iter_mut() {
+        transform.translation += offset.extend(0.0);
+        transform.rotation = Quat::from_rotation_z(angle);
+    }
+    shake.offset = offset;
+}
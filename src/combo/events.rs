@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+pub struct ComboChanged {
+    pub count: u32,
+    pub multiplier: u32,
+}
+
+pub struct NearMiss {
+    pub points: u32,
+    pub position: Vec3,
+}
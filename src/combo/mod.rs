@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+use crate::game::states::AppState;
+use events::*;
+use resources::*;
+use systems::*;
+
+pub const COMBO_WINDOW: f32 = 1.5;
+pub const COMBO_STARS_PER_LEVEL: u32 = 3;
+pub const MAX_COMBO_MULTIPLIER: u32 = 8;
+pub const NEAR_MISS_DISTANCE: f32 = 32.0;
+pub const NEAR_MISS_POINTS: u32 = 2;
+
+pub struct ComboPlugin;
+
+impl Plugin for ComboPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Combo>()
+            .init_resource::<NearMisses>()
+            .add_event::<ComboChanged>()
+            .add_event::<NearMiss>()
+            .add_systems((reset_combo, spawn_combo_text).in_schedule(OnEnter(AppState::Game)))
+            .add_system(despawn_combo_text.in_schedule(OnExit(AppState::Game)))
+            .add_systems(
+                (
+                    extend_combo,
+                    decay_combo,
+                    detect_near_misses,
+                    update_combo_text,
+                )
+                    .in_set(OnUpdate(AppState::Game)),
+            );
+    }
+}
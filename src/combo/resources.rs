@@ -0,0 +1,56 @@
+use super::{COMBO_STARS_PER_LEVEL, COMBO_WINDOW, MAX_COMBO_MULTIPLIER};
+use bevy::{prelude::*, utils::HashSet};
+
+/// Stars collected in quick succession raise the multiplier by one level every
+/// `COMBO_STARS_PER_LEVEL` pickups. Each time the timer runs out it drops back a level.
+#[derive(Resource)]
+pub struct Combo {
+    pub count: u32,
+    pub multiplier: u32,
+    pub timer: Timer,
+}
+
+impl Default for Combo {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(COMBO_WINDOW, TimerMode::Once);
+        timer.pause();
+        Self {
+            count: 0,
+            multiplier: 1,
+            timer,
+        }
+    }
+}
+
+impl Combo {
+    pub fn points(&self, base: u32) -> u32 {
+        base * self.multiplier
+    }
+
+    pub fn extend(&mut self) {
+        self.count += 1;
+        self.multiplier = (1 + self.count / COMBO_STARS_PER_LEVEL).min(MAX_COMBO_MULTIPLIER);
+        self.timer.reset();
+        self.timer.unpause();
+    }
+
+    /// Drops the multiplier by one level, returning false once there is nothing left to decay.
+    pub fn decay(&mut self) -> bool {
+        if self.count == 0 {
+            self.timer.pause();
+            return false;
+        }
+
+        self.multiplier = self.multiplier.saturating_sub(1).max(1);
+        self.count = (self.multiplier - 1) * COMBO_STARS_PER_LEVEL;
+        self.timer.reset();
+        if self.count == 0 {
+            self.timer.pause();
+        }
+        true
+    }
+}
+
+/// Player and enemy pairs currently inside the near-miss band around that player.
+#[derive(Resource, Default)]
+pub struct NearMisses(pub HashSet<(Entity, Entity)>);
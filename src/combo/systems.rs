@@ -0,0 +1,127 @@
+use super::{components::*, events::*, resources::*, NEAR_MISS_DISTANCE, NEAR_MISS_POINTS};
+use crate::{
+    arena::resources::Arena,
+    enemy::{components::Enemy, ENEMY_SIZE},
+    game_time::resources::GameTime,
+    loading::resources::GameAssets,
+    player::{components::Player, PLAYER_SIZE},
+    score::resources::{PlayerScores, Score},
+    star::events::StarCollected,
+};
+use bevy::prelude::*;
+
+pub fn reset_combo(mut combo: ResMut<Combo>, mut near_misses: ResMut<NearMisses>) {
+    *combo = Combo::default();
+    near_misses.0.clear();
+}
+
+pub fn extend_combo(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut combo_changed_event_writer: EventWriter<ComboChanged>,
+    mut combo: ResMut<Combo>,
+) {
+    for _ in star_collected_event_reader.iter() {
+        combo.extend();
+        combo_changed_event_writer.send(ComboChanged {
+            count: combo.count,
+            multiplier: combo.multiplier,
+        });
+    }
+}
+
+pub fn decay_combo(
+    mut combo_changed_event_writer: EventWriter<ComboChanged>,
+    mut combo: ResMut<Combo>,
+    game_time: Res<GameTime>,
+) {
+    if combo.timer.paused() {
+        return;
+    }
+
+    if combo.timer.tick(game_time.delta()).just_finished() && combo.decay() {
+        combo_changed_event_writer.send(ComboChanged {
+            count: combo.count,
+            multiplier: combo.multiplier,
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn detect_near_misses(
+    player_query: Query<(Entity, &Player, &Transform)>,
+    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    mut near_misses: ResMut<NearMisses>,
+    mut near_miss_event_writer: EventWriter<NearMiss>,
+    mut score: ResMut<Score>,
+    mut player_scores: ResMut<PlayerScores>,
+    combo: Res<Combo>,
+    arena: Res<Arena>,
+) {
+    let near_distance = (PLAYER_SIZE + ENEMY_SIZE) * 0.5 + NEAR_MISS_DISTANCE;
+
+    near_misses
+        .0
+        .retain(|(player, enemy)| player_query.contains(*player) && enemy_query.contains(*enemy));
+
+    for (player_entity, player, player_transform) in player_query.iter() {
+        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+            let pair = (player_entity, enemy_entity);
+            let distance =
+                arena.distance(player_transform.translation, enemy_transform.translation);
+            if distance < near_distance {
+                near_misses.0.insert(pair);
+            } else if near_misses.0.remove(&pair) {
+                let points = combo.points(NEAR_MISS_POINTS);
+                score.0 += points;
+                player_scores.add(player.index, points);
+                near_miss_event_writer.send(NearMiss {
+                    points,
+                    position: enemy_transform.translation,
+                });
+            }
+        }
+    }
+}
+
+pub fn spawn_combo_text(mut commands: Commands, game_assets: Res<GameAssets>) {
+    commands.spawn((
+        ComboText {},
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: game_assets.font.clone(),
+                font_size: 32.0,
+                color: Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                right: Val::Px(10.0),
+                top: Val::Px(10.0),
+                ..default()
+            },
+            ..default()
+        }),
+    ));
+}
+
+pub fn despawn_combo_text(mut commands: Commands, query: Query<Entity, With<ComboText>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn update_combo_text(mut query: Query<&mut Text, With<ComboText>>, combo: Res<Combo>) {
+    for mut text in query.iter_mut() {
+        text.sections[0].value = if combo.multiplier > 1 {
+            format!(
+                "x{} ({:.1}s)",
+                combo.multiplier,
+                combo.timer.remaining_secs()
+            )
+        } else {
+            String::new()
+        };
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct EditorHud {}
+
+#[derive(Component)]
+pub struct SelectionMarker {}
+
+#[derive(Component)]
+pub struct DirectionMarker {}
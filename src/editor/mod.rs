@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+use crate::game::states::AppState;
+use resources::*;
+use systems::*;
+
+pub const EDITOR_PAN_SPEED: f32 = 800.0;
+pub const EDITOR_SCALE_STEP: f32 = 1.1;
+pub const EDITOR_MIN_OBSTACLE_SIZE: f32 = 16.0;
+pub const STAR_CLUSTER_SIZE: usize = 6;
+pub const STAR_CLUSTER_RADIUS: f32 = 48.0;
+pub const NEW_LEVEL_PREFIX: &str = "custom";
+pub const SELECTION_COLOR: Color = Color::rgba(1.0, 0.85, 0.3, 0.35);
+pub const DIRECTION_MARKER_COLOR: Color = Color::rgb(1.0, 0.85, 0.3);
+pub const DIRECTION_MARKER_SIZE: f32 = 12.0;
+
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelEditor>()
+            .init_resource::<EditorCursor>()
+            .add_system(open_editor.in_set(OnUpdate(AppState::LevelSelect)))
+            .add_system(end_playtest.in_schedule(OnEnter(AppState::LevelSelect)))
+            .add_system(end_playtest.in_schedule(OnEnter(AppState::MainMenu)))
+            .add_systems(
+                (spawn_editor_hud, spawn_selection_marker).in_schedule(OnEnter(AppState::Editor)),
+            )
+            .add_systems(
+                (despawn_editor_hud, despawn_selection_marker)
+                    .in_schedule(OnExit(AppState::Editor)),
+            )
+            .add_systems(
+                (
+                    update_editor_cursor,
+                    select_tool,
+                    place_or_select,
+                    drag_selection,
+                    aim_enemy,
+                    scale_obstacle,
+                    delete_selection,
+                )
+                    .chain()
+                    .in_set(OnUpdate(AppState::Editor)),
+            )
+            .add_systems(
+                (
+                    pan_editor_camera,
+                    save_level,
+                    start_playtest,
+                    leave_editor,
+                    mark_enemy_directions,
+                    update_selection_marker,
+                    update_editor_hud,
+                )
+                    .in_set(OnUpdate(AppState::Editor)),
+            )
+            .add_system(return_to_editor);
+    }
+}
@@ -0,0 +1,233 @@
+use super::{STAR_CLUSTER_RADIUS, STAR_CLUSTER_SIZE};
+use crate::{
+    arena::resources::Arena,
+    enemy::ENEMY_SIZE,
+    level::resources::{EnemyDefinition, Level, ObstacleDefinition},
+    obstacle::components::Shape,
+    player::PLAYER_SIZE,
+    star::STAR_SIZE,
+};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EditorTool {
+    #[default]
+    Select,
+    Rectangle,
+    Circle,
+    Polygon,
+    Enemy,
+    StarCluster,
+    Player,
+}
+
+impl EditorTool {
+    pub const ALL: [EditorTool; 7] = [
+        EditorTool::Select,
+        EditorTool::Rectangle,
+        EditorTool::Circle,
+        EditorTool::Polygon,
+        EditorTool::Enemy,
+        EditorTool::StarCluster,
+        EditorTool::Player,
+    ];
+
+    pub fn key(&self) -> KeyCode {
+        match self {
+            EditorTool::Select => KeyCode::Key1,
+            EditorTool::Rectangle => KeyCode::Key2,
+            EditorTool::Circle => KeyCode::Key3,
+            EditorTool::Polygon => KeyCode::Key4,
+            EditorTool::Enemy => KeyCode::Key5,
+            EditorTool::StarCluster => KeyCode::Key6,
+            EditorTool::Player => KeyCode::Key7,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EditorTool::Select => "Select",
+            EditorTool::Rectangle => "Rectangle",
+            EditorTool::Circle => "Circle",
+            EditorTool::Polygon => "Polygon",
+            EditorTool::Enemy => "Enemy",
+            EditorTool::StarCluster => "Stars",
+            EditorTool::Player => "Player",
+        }
+    }
+
+    /// Adds whatever the tool places to the level, returning the new selection.
+    pub fn place(&self, level: &mut Level, arena: &Arena, point: Vec2) -> Option<Selection> {
+        let obstacle = |level: &mut Level, shape: Shape| {
+            level.obstacles.push(ObstacleDefinition {
+                position: point,
+                shape,
+            });
+            Some(Selection::Obstacle(level.obstacles.len() - 1))
+        };
+
+        match self {
+            EditorTool::Select => Selection::pick(level, arena, point),
+            EditorTool::Rectangle => obstacle(
+                level,
+                Shape::Rectangle {
+                    width: 128.0,
+                    height: 64.0,
+                },
+            ),
+            EditorTool::Circle => obstacle(level, Shape::Circle { radius: 48.0 }),
+            EditorTool::Polygon => obstacle(
+                level,
+                Shape::Polygon {
+                    points: (0..6)
+                        .map(|i| Vec2::from_angle(i as f32 * TAU / 6.0) * 56.0)
+                        .collect(),
+                },
+            ),
+            EditorTool::Enemy => {
+                level.enemies.push(EnemyDefinition {
+                    position: point,
+                    direction: Vec2::ONE.normalize(),
+                });
+                Some(Selection::Enemy(level.enemies.len() - 1))
+            }
+            EditorTool::StarCluster => {
+                let (min, max) = arena.bounds(STAR_SIZE * 0.5);
+                let ring = (0..STAR_CLUSTER_SIZE).map(|i| {
+                    point
+                        + Vec2::from_angle(i as f32 * TAU / STAR_CLUSTER_SIZE as f32)
+                            * STAR_CLUSTER_RADIUS
+                });
+                let positions: Vec<Vec2> = std::iter::once(point)
+                    .chain(ring)
+                    .filter(|position| position.clamp(min, max) == *position)
+                    .filter(|position| !level.is_blocked(*position, STAR_SIZE * 0.5))
+                    .collect();
+                if positions.is_empty() {
+                    return None;
+                }
+                let first = level.stars.len();
+                level.stars.extend(positions);
+                Some(Selection::Star(first))
+            }
+            EditorTool::Player => {
+                level.player = Some(point);
+                Some(Selection::Player)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Selection {
+    Player,
+    Enemy(usize),
+    Star(usize),
+    Obstacle(usize),
+}
+
+impl Selection {
+    /// Topmost level element under `point`, in the order they are drawn.
+    pub fn pick(level: &Level, arena: &Arena, point: Vec2) -> Option<Self> {
+        let within = |position: Vec2, size: f32| position.distance(point) <= size * 0.5;
+
+        if within(level.player_position(arena), PLAYER_SIZE) {
+            return Some(Selection::Player);
+        }
+        if let Some(index) = level
+            .enemies
+            .iter()
+            .rposition(|enemy| within(enemy.position, ENEMY_SIZE))
+        {
+            return Some(Selection::Enemy(index));
+        }
+        if let Some(index) = level
+            .stars
+            .iter()
+            .rposition(|star| within(*star, STAR_SIZE))
+        {
+            return Some(Selection::Star(index));
+        }
+        level
+            .obstacles
+            .iter()
+            .rposition(|obstacle| obstacle.shape.contains(point - obstacle.position))
+            .map(Selection::Obstacle)
+    }
+
+    pub fn position(&self, level: &Level, arena: &Arena) -> Option<Vec2> {
+        match *self {
+            Selection::Player => Some(level.player_position(arena)),
+            Selection::Enemy(index) => level.enemies.get(index).map(|enemy| enemy.position),
+            Selection::Star(index) => level.stars.get(index).copied(),
+            Selection::Obstacle(index) => {
+                level.obstacles.get(index).map(|obstacle| obstacle.position)
+            }
+        }
+    }
+
+    pub fn set_position(&self, level: &mut Level, position: Vec2) {
+        match *self {
+            Selection::Player => level.player = Some(position),
+            Selection::Enemy(index) => {
+                if let Some(enemy) = level.enemies.get_mut(index) {
+                    enemy.position = position;
+                }
+            }
+            Selection::Star(index) => {
+                if let Some(star) = level.stars.get_mut(index) {
+                    *star = position;
+                }
+            }
+            Selection::Obstacle(index) => {
+                if let Some(obstacle) = level.obstacles.get_mut(index) {
+                    obstacle.position = position;
+                }
+            }
+        }
+    }
+
+    pub fn size(&self, level: &Level) -> Option<Vec2> {
+        match *self {
+            Selection::Player => Some(Vec2::splat(PLAYER_SIZE)),
+            Selection::Enemy(_) => Some(Vec2::splat(ENEMY_SIZE)),
+            Selection::Star(_) => Some(Vec2::splat(STAR_SIZE)),
+            Selection::Obstacle(index) => level
+                .obstacles
+                .get(index)
+                .map(|obstacle| obstacle.shape.size()),
+        }
+    }
+
+    /// Removes the selected element. The player cannot be removed, so it returns to the
+    /// arena centre instead.
+    pub fn remove(&self, level: &mut Level) {
+        match *self {
+            Selection::Player => level.player = None,
+            Selection::Enemy(index) if index < level.enemies.len() => {
+                level.enemies.remove(index);
+            }
+            Selection::Star(index) if index < level.stars.len() => {
+                level.stars.remove(index);
+            }
+            Selection::Obstacle(index) if index < level.obstacles.len() => {
+                level.obstacles.remove(index);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct LevelEditor {
+    pub file: String,
+    pub tool: EditorTool,
+    pub selection: Option<Selection>,
+    pub drag_offset: Option<Vec2>,
+    pub testing: bool,
+    pub status: String,
+}
+
+#[derive(Resource, Default)]
+pub struct EditorCursor(pub Option<Vec2>);
@@ -0,0 +1,422 @@
+use super::{components::*, resources::*};
+use super::{
+    DIRECTION_MARKER_COLOR, DIRECTION_MARKER_SIZE, EDITOR_MIN_OBSTACLE_SIZE, EDITOR_PAN_SPEED,
+    EDITOR_SCALE_STEP, NEW_LEVEL_PREFIX, SELECTION_COLOR,
+};
+use crate::{
+    arena::resources::Arena,
+    enemy::{components::Enemy, ENEMY_SIZE},
+    game::{components::MainCamera, states::AppState},
+    level::resources::{Level, LevelCatalog},
+    loading::resources::GameAssets,
+    settings::resources::Settings,
+};
+use bevy::{input::mouse::MouseWheel, prelude::*, window::PrimaryWindow};
+
+pub fn open_editor(
+    keyboard_input: Res<Input<KeyCode>>,
+    catalog: Res<LevelCatalog>,
+    mut editor: ResMut<LevelEditor>,
+    mut level: ResMut<Level>,
+    mut arena: ResMut<Arena>,
+    settings: Res<Settings>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let (file, loaded) = if keyboard_input.just_pressed(KeyCode::E) {
+        let Some(file) = catalog.selected_file() else {
+            return;
+        };
+        match Level::load(file) {
+            Ok(loaded) => (file.to_string(), loaded),
+            Err(err) => {
+                error!("{}", err);
+                return;
+            }
+        }
+    } else if keyboard_input.just_pressed(KeyCode::N) {
+        let number = (1..)
+            .find(|n| !Level::path(&format!("{}_{}", NEW_LEVEL_PREFIX, n)).exists())
+            .unwrap_or_default();
+        let loaded = Level {
+            name: format!("Custom {}", number),
+            ..default()
+        };
+        (format!("{}_{}", NEW_LEVEL_PREFIX, number), loaded)
+    } else {
+        return;
+    };
+
+    *arena = loaded.arena(&settings.world);
+    *level = loaded;
+    *editor = LevelEditor { file, ..default() };
+    next_state.set(AppState::Editor);
+}
+
+pub fn end_playtest(mut editor: ResMut<LevelEditor>) {
+    if editor.testing {
+        editor.testing = false;
+    }
+}
+
+pub fn spawn_editor_hud(mut commands: Commands, game_assets: Res<GameAssets>) {
+    commands.spawn((
+        EditorHud {},
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: game_assets.font.clone(),
+                font_size: 20.0,
+                color: Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                left: Val::Px(10.0),
+                top: Val::Px(10.0),
+                ..default()
+            },
+            ..default()
+        }),
+    ));
+}
+
+pub fn despawn_editor_hud(mut commands: Commands, query: Query<Entity, With<EditorHud>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn update_editor_hud(mut query: Query<(&mut Text, Ref<EditorHud>)>, editor: Res<LevelEditor>) {
+    for (mut text, hud) in query.iter_mut() {
+        if !editor.is_changed() && !hud.is_added() {
+            continue;
+        }
+
+        let tools: Vec<String> = EditorTool::ALL
+            .iter()
+            .enumerate()
+            .map(|(index, tool)| {
+                if *tool == editor.tool {
+                    format!("[{} {}]", index + 1, tool.name())
+                } else {
+                    format!("{} {}", index + 1, tool.name())
+                }
+            })
+            .collect();
+
+        text.sections[0].value = format!(
+            "Editing {}\n{}\nLeft click to place or drag, right drag to aim enemies, wheel to resize\n\
+             Delete to remove, arrows to pan, Ctrl+S to save, Tab to play, Backspace to leave\n{}",
+            Level::path(&editor.file).display(),
+            tools.join("  "),
+            editor.status,
+        );
+    }
+}
+
+pub fn update_editor_cursor(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut cursor: ResMut<EditorCursor>,
+) {
+    let position = window_query
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .zip(camera_query.get_single().ok())
+        .and_then(|(position, (camera, transform))| {
+            camera.viewport_to_world_2d(transform, position)
+        });
+
+    if cursor.0 != position {
+        cursor.0 = position;
+    }
+}
+
+pub fn select_tool(keyboard_input: Res<Input<KeyCode>>, mut editor: ResMut<LevelEditor>) {
+    for tool in EditorTool::ALL {
+        if keyboard_input.just_pressed(tool.key()) && editor.tool != tool {
+            editor.tool = tool;
+        }
+    }
+}
+
+pub fn place_or_select(
+    mouse_input: Res<Input<MouseButton>>,
+    cursor: Res<EditorCursor>,
+    mut editor: ResMut<LevelEditor>,
+    mut level: ResMut<Level>,
+    arena: Res<Arena>,
+) {
+    if !mouse_input.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Some(point) = cursor.0 else {
+        return;
+    };
+    if point.clamp(Vec2::ZERO, arena.size()) != point {
+        return;
+    }
+
+    let selection = if editor.tool == EditorTool::Select {
+        Selection::pick(&level, &arena, point)
+    } else {
+        editor.tool.place(&mut level, &arena, point)
+    };
+
+    editor.drag_offset = selection
+        .and_then(|selection| selection.position(&level, &arena))
+        .map(|position| position - point);
+    editor.selection = selection;
+}
+
+pub fn drag_selection(
+    mouse_input: Res<Input<MouseButton>>,
+    cursor: Res<EditorCursor>,
+    mut editor: ResMut<LevelEditor>,
+    mut level: ResMut<Level>,
+    arena: Res<Arena>,
+) {
+    if !mouse_input.pressed(MouseButton::Left) {
+        if editor.drag_offset.is_some() {
+            editor.drag_offset = None;
+        }
+        return;
+    }
+    let (Some(selection), Some(offset), Some(point)) =
+        (editor.selection, editor.drag_offset, cursor.0)
+    else {
+        return;
+    };
+
+    let position = (point + offset).clamp(Vec2::ZERO, arena.size());
+    if selection.position(&level, &arena) != Some(position) {
+        selection.set_position(&mut level, position);
+    }
+}
+
+pub fn aim_enemy(
+    mouse_input: Res<Input<MouseButton>>,
+    cursor: Res<EditorCursor>,
+    editor: Res<LevelEditor>,
+    mut level: ResMut<Level>,
+) {
+    if !mouse_input.pressed(MouseButton::Right) {
+        return;
+    }
+    let (Some(Selection::Enemy(index)), Some(point)) = (editor.selection, cursor.0) else {
+        return;
+    };
+    let Some(enemy) = level.enemies.get(index) else {
+        return;
+    };
+
+    let direction = (point - enemy.position).normalize_or_zero();
+    if direction != Vec2::ZERO && direction != enemy.direction {
+        level.enemies[index].direction = direction;
+    }
+}
+
+pub fn scale_obstacle(
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    editor: Res<LevelEditor>,
+    mut level: ResMut<Level>,
+) {
+    let factor =
+        mouse_wheel_events
+            .iter()
+            .fold(1.0, |factor, event| match event.y.partial_cmp(&0.0) {
+                Some(std::cmp::Ordering::Greater) => factor * EDITOR_SCALE_STEP,
+                Some(std::cmp::Ordering::Less) => factor / EDITOR_SCALE_STEP,
+                _ => factor,
+            });
+    if factor == 1.0 {
+        return;
+    }
+    let Some(Selection::Obstacle(index)) = editor.selection else {
+        return;
+    };
+    let Some(obstacle) = level.obstacles.get(index) else {
+        return;
+    };
+
+    let shape = obstacle.shape.scaled(factor);
+    if shape.size().min_element() >= EDITOR_MIN_OBSTACLE_SIZE {
+        level.obstacles[index].shape = shape;
+    }
+}
+
+pub fn delete_selection(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut editor: ResMut<LevelEditor>,
+    mut level: ResMut<Level>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Delete) && !keyboard_input.just_pressed(KeyCode::X) {
+        return;
+    }
+    let Some(selection) = editor.selection else {
+        return;
+    };
+
+    selection.remove(&mut level);
+    editor.selection = None;
+    editor.drag_offset = None;
+}
+
+pub fn pan_editor_camera(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    arena: Res<Arena>,
+    time: Res<Time>,
+) {
+    let mut direction = Vec2::ZERO;
+    if keyboard_input.pressed(KeyCode::Left) {
+        direction.x -= 1.0;
+    }
+    if keyboard_input.pressed(KeyCode::Right) {
+        direction.x += 1.0;
+    }
+    if keyboard_input.pressed(KeyCode::Down) {
+        direction.y -= 1.0;
+    }
+    if keyboard_input.pressed(KeyCode::Up) {
+        direction.y += 1.0;
+    }
+    if direction == Vec2::ZERO {
+        return;
+    }
+
+    for mut transform in camera_query.iter_mut() {
+        let position = arena.clamp_camera(
+            transform.translation.truncate()
+                + direction.normalize() * EDITOR_PAN_SPEED * time.delta_seconds(),
+        );
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+pub fn save_level(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut editor: ResMut<LevelEditor>,
+    level: Res<Level>,
+) {
+    let control =
+        keyboard_input.pressed(KeyCode::LControl) || keyboard_input.pressed(KeyCode::RControl);
+    if !control || !keyboard_input.just_pressed(KeyCode::S) {
+        return;
+    }
+
+    editor.status = match level.save(&editor.file) {
+        Ok(()) => format!("Saved {}", Level::path(&editor.file).display()),
+        Err(err) => {
+            error!("{}", err);
+            err
+        }
+    };
+}
+
+pub fn start_playtest(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut editor: ResMut<LevelEditor>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Tab) {
+        editor.testing = true;
+        editor.drag_offset = None;
+        next_state.set(AppState::Game);
+    }
+}
+
+pub fn return_to_editor(
+    keyboard_input: Res<Input<KeyCode>>,
+    editor: Res<LevelEditor>,
+    state: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let playing = matches!(
+        state.0,
+        AppState::Game | AppState::GameOver | AppState::LevelComplete
+    );
+    if editor.testing && playing && keyboard_input.just_pressed(KeyCode::Tab) {
+        next_state.set(AppState::Editor);
+    }
+}
+
+pub fn leave_editor(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Back) {
+        next_state.set(AppState::LevelSelect);
+    }
+}
+
+pub fn mark_enemy_directions(mut commands: Commands, query: Query<(Entity, &Enemy), Added<Enemy>>) {
+    for (entity, enemy) in query.iter() {
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                DirectionMarker {},
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: DIRECTION_MARKER_COLOR,
+                        custom_size: Some(Vec2::splat(DIRECTION_MARKER_SIZE)),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(
+                        (enemy.direction * ENEMY_SIZE * 0.7).extend(0.1),
+                    ),
+                    ..default()
+                },
+            ));
+        });
+    }
+}
+
+pub fn spawn_selection_marker(mut commands: Commands) {
+    commands.spawn((
+        SelectionMarker {},
+        SpriteBundle {
+            sprite: Sprite {
+                color: SELECTION_COLOR,
+                ..default()
+            },
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+    ));
+}
+
+pub fn despawn_selection_marker(
+    mut commands: Commands,
+    query: Query<Entity, With<SelectionMarker>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn update_selection_marker(
+    mut query: Query<(&mut Transform, &mut Sprite, &mut Visibility), With<SelectionMarker>>,
+    editor: Res<LevelEditor>,
+    level: Res<Level>,
+    arena: Res<Arena>,
+) {
+    let Ok((mut transform, mut sprite, mut visibility)) = query.get_single_mut() else {
+        return;
+    };
+
+    let placement = editor
+        .selection
+        .and_then(|selection| Some((selection.position(&level, &arena)?, selection.size(&level)?)));
+
+    match placement {
+        Some((position, size)) => {
+            *visibility = Visibility::Inherited;
+            transform.translation = position.extend(5.0);
+            sprite.custom_size = Some(size + 8.0);
+        }
+        None => *visibility = Visibility::Hidden,
+    }
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+pub struct EnemyDestroyed {
+    pub points: u32,
+    pub position: Vec3,
+}
@@ -1,28 +1,59 @@
 use bevy::prelude::*;
 
 pub mod components;
+pub mod events;
 pub mod resources;
 mod systems;
 
+use crate::{
+    animation::components::{AnimationClip, AnimationMode},
+    game::states::AppState,
+    level::resources::Level,
+};
+use events::*;
 use resources::*;
 use systems::*;
 
-pub const INITIAL_NUMBER_OF_ENEMIES: usize = 4;
 pub const MAX_NUMBER_OF_ENEMIES: usize = 20;
 pub const ENEMY_SPEED: f32 = 200.0;
 pub const ENEMY_SIZE: f32 = 64.0;
 pub const ENEMY_SPAWN_INTERVAL: f32 = 0.5;
+pub const ENEMY_PULSE: AnimationClip = AnimationClip {
+    frames: 8,
+    fps: 12.0,
+    mode: AnimationMode::Loop,
+};
+
+/// Moves enemies, so systems deflecting them can run after.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnemyMovementSet;
 
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<EnemySpawnTimer>()
-            .add_startup_system(spawn_enemies)
-            .add_system(enemy_movement)
-            .add_system(update_enemy_direction)
-            .add_system(confine_enemy_movement)
-            .add_system(tick_enemy_spawn_timer)
-            .add_system(spawn_enemies_over_time);
+            .add_event::<EnemyDestroyed>()
+            .add_system(spawn_enemies.in_schedule(OnEnter(AppState::Game)))
+            .add_system(reset_enemy_spawn_timer.in_schedule(OnEnter(AppState::Game)))
+            .add_system(despawn_enemies.in_schedule(OnExit(AppState::Game)))
+            .add_system(spawn_enemies.in_schedule(OnEnter(AppState::Editor)))
+            .add_system(despawn_enemies.in_schedule(OnExit(AppState::Editor)))
+            .add_systems(
+                (despawn_enemies, spawn_enemies)
+                    .chain()
+                    .distributive_run_if(resource_changed::<Level>())
+                    .in_set(OnUpdate(AppState::Editor)),
+            )
+            .add_systems(
+                (
+                    enemy_movement.in_set(EnemyMovementSet),
+                    update_enemy_direction,
+                    confine_enemy_movement,
+                    tick_enemy_spawn_timer,
+                    spawn_enemies_over_time,
+                )
+                    .in_set(OnUpdate(AppState::Game)),
+            );
     }
 }
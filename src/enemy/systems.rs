@@ -1,149 +1,126 @@
-use super::{
-    components::*, resources::*, ENEMY_SIZE, ENEMY_SPEED, INITIAL_NUMBER_OF_ENEMIES,
-    MAX_NUMBER_OF_ENEMIES,
+use super::{components::*, resources::*, ENEMY_PULSE, ENEMY_SIZE, ENEMY_SPEED};
+use crate::{
+    animation::components::Animation,
+    arena::resources::Arena,
+    game_time::resources::GameTime,
+    level::resources::Level,
+    loading::resources::GameAssets,
+    sound::{events::PlaySoundEffect, resources::SoundEffect},
 };
-use crate::player::PLAYER_SIZE;
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::prelude::*;
 use rand::prelude::*;
-use std::f32::consts::PI;
-
-pub fn spawn_enemies(
-    mut commands: Commands,
-    query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-) {
-    let window = query.get_single().unwrap();
-    let width = window.width();
-    let height = window.height();
-
-    for _ in 0..INITIAL_NUMBER_OF_ENEMIES {
-        let theta = random::<f32>() * PI * 2.0;
-        let direction = Vec2::new(theta.cos(), theta.sin());
-        let offset = direction * ((ENEMY_SIZE + PLAYER_SIZE) * 0.5001);
+use std::{f32::consts::PI, time::Duration};
 
+pub fn spawn_enemies(mut commands: Commands, level: Res<Level>, game_assets: Res<GameAssets>) {
+    for definition in level.enemies.iter() {
         commands.spawn((
-            Enemy { direction },
-            SpriteBundle {
-                transform: Transform::from_xyz(
-                    (width * 0.5) + offset.x,
-                    (height * 0.5) + offset.y,
-                    0.0,
-                ),
-                texture: asset_server.load("sprites/ball_red_large.png"),
+            Enemy {
+                direction: definition.direction.normalize_or_zero(),
+            },
+            Animation::new(ENEMY_PULSE).starting_at(random()),
+            SpriteSheetBundle {
+                transform: Transform::from_translation(definition.position.extend(0.0)),
+                texture_atlas: game_assets.enemy_atlas.clone(),
                 ..default()
             },
         ));
     }
 }
 
-pub fn enemy_movement(mut query: Query<(&mut Transform, &Enemy)>, time: Res<Time>) {
+pub fn despawn_enemies(mut commands: Commands, query: Query<Entity, With<Enemy>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn enemy_movement(mut query: Query<(&mut Transform, &Enemy)>, game_time: Res<GameTime>) {
+    let speed = ENEMY_SPEED * game_time.enemy_scale();
+
     for (mut transform, enemy) in query.iter_mut() {
         let direction = Vec3::new(enemy.direction.x, enemy.direction.y, 0.0);
-        transform.translation += direction * ENEMY_SPEED * time.delta_seconds();
+        transform.translation += direction * speed * game_time.delta_seconds();
     }
 }
 
 pub fn update_enemy_direction(
     mut query: Query<(&mut Enemy, &Transform)>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    audio: Res<Audio>,
-    asset_server: Res<AssetServer>,
+    arena: Res<Arena>,
+    mut play_sound_event_writer: EventWriter<PlaySoundEffect>,
 ) {
-    let window = window_query.get_single().unwrap();
-    let width = window.width();
-    let height = window.height();
-
-    let half_enemy_size = ENEMY_SIZE * 0.5;
-    let x_min = half_enemy_size;
-    let x_max = width - half_enemy_size;
-    let y_min = half_enemy_size;
-    let y_max = height - half_enemy_size;
+    if arena.wraps() {
+        return;
+    }
 
-    let sound_effect_0 = asset_server.load("audio/pluck_000.ogg");
-    let sound_effect_1 = asset_server.load("audio/pluck_001.ogg");
+    let (min, max) = arena.bounds(ENEMY_SIZE * 0.5);
 
-    let mut changed_direction = false;
     for (mut enemy, transform) in query.iter_mut() {
+        let mut changed_direction = false;
+
         let translation = transform.translation;
-        if translation.x < x_min || translation.x > x_max {
+        if translation.x < min.x || translation.x > max.x {
             enemy.direction.x *= -1.0;
             changed_direction = true;
         }
-        if translation.y < y_min || translation.y > y_max {
+        if translation.y < min.y || translation.y > max.y {
             enemy.direction.y *= -1.0;
             changed_direction = true;
         }
-    }
 
-    if changed_direction {
-        let effect = match random::<bool>() {
-            true => sound_effect_0.clone(),
-            false => sound_effect_1.clone(),
-        };
-        audio.play(effect);
+        if changed_direction {
+            play_sound_event_writer.send(PlaySoundEffect {
+                effect: SoundEffect::Bounce,
+                position: Some(translation),
+            });
+        }
     }
 }
 
 pub fn confine_enemy_movement(
     mut enemy_query: Query<&mut Transform, With<Enemy>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
 ) {
-    let window = window_query.get_single().unwrap();
-    let width = window.width();
-    let height = window.height();
-
-    let half_enemy_size = PLAYER_SIZE * 0.5;
-    let x_min = half_enemy_size;
-    let x_max = width - half_enemy_size;
-    let y_min = half_enemy_size;
-    let y_max = height - half_enemy_size;
-
     for mut transform in enemy_query.iter_mut() {
-        if transform.translation.x < x_min {
-            transform.translation.x = x_min;
-        } else if transform.translation.x > x_max {
-            transform.translation.x = x_max;
-        }
-        if transform.translation.y < y_min {
-            transform.translation.y = y_min;
-        } else if transform.translation.y > y_max {
-            transform.translation.y = y_max;
-        }
+        transform.translation = arena.confine(transform.translation, ENEMY_SIZE * 0.5);
     }
 }
 
-pub fn tick_enemy_spawn_timer(mut enemy_spawn_timer: ResMut<EnemySpawnTimer>, time: Res<Time>) {
-    enemy_spawn_timer.0.tick(time.delta());
+pub fn reset_enemy_spawn_timer(mut enemy_spawn_timer: ResMut<EnemySpawnTimer>, level: Res<Level>) {
+    enemy_spawn_timer.0 = Timer::new(
+        Duration::from_secs_f32(level.spawns.enemy_interval),
+        TimerMode::Repeating,
+    );
+}
+
+pub fn tick_enemy_spawn_timer(
+    mut enemy_spawn_timer: ResMut<EnemySpawnTimer>,
+    game_time: Res<GameTime>,
+) {
+    enemy_spawn_timer.0.tick(game_time.delta());
 }
 
 pub fn spawn_enemies_over_time(
     mut commands: Commands,
     enemy_query: Query<&Enemy>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    arena: Res<Arena>,
+    level: Res<Level>,
+    game_assets: Res<GameAssets>,
     enemy_spawn_timer: Res<EnemySpawnTimer>,
 ) {
     if enemy_spawn_timer.0.finished() {
-        if enemy_query.iter().count() >= MAX_NUMBER_OF_ENEMIES {
+        if enemy_query.iter().count() >= level.spawns.max_enemies {
             return;
         }
 
-        let window = window_query.get_single().unwrap();
-        let width = window.width();
-        let height = window.height();
-
+        let position = arena.random_position();
         let theta = random::<f32>() * 2.0 * PI;
         commands.spawn((
             Enemy {
                 direction: Vec2::new(theta.cos(), theta.sin()),
             },
-            SpriteBundle {
-                transform: Transform::from_xyz(
-                    random::<f32>() * width,
-                    random::<f32>() * height,
-                    -1.0,
-                ),
-                texture: asset_server.load("sprites/ball_red_large.png"),
+            Animation::new(ENEMY_PULSE).starting_at(random()),
+            SpriteSheetBundle {
+                transform: Transform::from_translation(position.extend(-1.0)),
+                texture_atlas: game_assets.enemy_atlas.clone(),
                 ..default()
             },
         ));
@@ -1,16 +1,22 @@
 use bevy::prelude::*;
 
+pub mod components;
 pub mod events;
+pub mod states;
 mod systems;
 
 use events::*;
+use states::*;
 use systems::*;
 
+pub const LETTERBOX_COLOR: Color = Color::BLACK;
+
 pub struct GamePlugin;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<GameOver>()
+        app.add_state::<AppState>()
+            .add_event::<GameOver>()
             .add_startup_system(spawn_camera)
             .add_system(exit_game)
             .add_system(handle_game_over);
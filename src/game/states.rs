@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+#[derive(States, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum AppState {
+    #[default]
+    Loading,
+    MainMenu,
+    LevelSelect,
+    Game,
+    GameOver,
+    LevelComplete,
+    Editor,
+    /// Following a game simulated by a networked host.
+    Online,
+}
@@ -1,15 +1,24 @@
-use super::events::*;
-use bevy::{app::AppExit, prelude::*, window::PrimaryWindow};
+use super::{components::*, events::*, states::*, LETTERBOX_COLOR};
+use crate::arena::resources::Arena;
+use bevy::{
+    app::AppExit, core_pipeline::clear_color::ClearColorConfig, prelude::*,
+    render::camera::ScalingMode,
+};
 
-pub fn spawn_camera(mut commands: Commands, query: Query<&Window, With<PrimaryWindow>>) {
-    let windows = query.get_single().unwrap();
-    let width = windows.width();
-    let height = windows.height();
-
-    commands.spawn(Camera2dBundle {
-        transform: Transform::from_xyz(width * 0.5, height * 0.5, 0.0),
+pub fn spawn_camera(mut commands: Commands, arena: Res<Arena>) {
+    let mut camera = Camera2dBundle {
+        transform: Transform::from_translation(arena.clamp_camera(arena.center()).extend(0.0)),
+        camera_2d: Camera2d {
+            clear_color: ClearColorConfig::Custom(LETTERBOX_COLOR),
+        },
         ..default()
-    });
+    };
+    camera.projection.scaling_mode = ScalingMode::AutoMin {
+        min_width: arena.view_width,
+        min_height: arena.view_height,
+    };
+
+    commands.spawn((MainCamera {}, camera));
 }
 
 pub fn exit_game(keyboard_input: Res<Input<KeyCode>>, mut exit: EventWriter<AppExit>) {
@@ -18,8 +27,12 @@ pub fn exit_game(keyboard_input: Res<Input<KeyCode>>, mut exit: EventWriter<AppE
     }
 }
 
-pub fn handle_game_over(mut game_over_event_writer: EventReader<GameOver>) {
+pub fn handle_game_over(
+    mut game_over_event_writer: EventReader<GameOver>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
     for game_over in game_over_event_writer.iter() {
         println!("Game Over! Score: {}", game_over.0);
+        next_state.set(AppState::GameOver);
     }
 }
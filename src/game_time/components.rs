@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct TimeScaleHud {}
+
+#[derive(Component)]
+pub struct TimeScaleText {}
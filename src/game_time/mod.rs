@@ -0,0 +1,34 @@
+use bevy::{prelude::*, time::TimeSystem};
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+use crate::game::states::AppState;
+use resources::*;
+use systems::*;
+
+pub const MIN_TIME_SCALE: f32 = 0.25;
+pub const MAX_TIME_SCALE: f32 = 4.0;
+
+pub struct GameTimePlugin;
+
+impl Plugin for GameTimePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameTime>()
+            .add_system(
+                update_game_time
+                    .in_base_set(CoreSet::First)
+                    .after(TimeSystem),
+            )
+            .add_system(spawn_time_scale_text.in_schedule(OnEnter(AppState::Game)))
+            .add_systems(
+                (reset_game_time, despawn_time_scale_text).in_schedule(OnExit(AppState::Game)),
+            )
+            .add_system(reset_game_time.in_schedule(OnExit(AppState::Online)))
+            .add_systems(
+                (toggle_pause, change_time_scale, update_time_scale_text)
+                    .in_set(OnUpdate(AppState::Game)),
+            );
+    }
+}
@@ -0,0 +1,81 @@
+use bevy::{prelude::*, utils::HashMap};
+use std::time::Duration;
+
+/// Reasons the game clock can be slowed or stopped on top of the base scale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeModifier {
+    Pause,
+    HitStop,
+}
+
+/// Clock for everything that happens in the game world. It follows real time multiplied
+/// by `scale` and any active modifiers, so 0 pauses, below 1 is slow motion and above 1
+/// fast-forwards. Menus, music and the camera keep using `Time`.
+#[derive(Resource)]
+pub struct GameTime {
+    pub scale: f32,
+    modifiers: HashMap<TimeModifier, f32>,
+    enemy_scale: f32,
+    delta: Duration,
+    elapsed: Duration,
+}
+
+impl Default for GameTime {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            modifiers: HashMap::default(),
+            enemy_scale: 1.0,
+            delta: Duration::ZERO,
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+impl GameTime {
+    pub fn effective_scale(&self) -> f32 {
+        self.scale * self.modifiers.values().product::<f32>()
+    }
+
+    /// Extra factor enemies move at on top of the game clock, for slowing them alone.
+    pub fn enemy_scale(&self) -> f32 {
+        self.enemy_scale
+    }
+
+    pub fn set_enemy_scale(&mut self, factor: f32) {
+        self.enemy_scale = factor;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.effective_scale() <= 0.0
+    }
+
+    pub fn has_modifier(&self, modifier: TimeModifier) -> bool {
+        self.modifiers.contains_key(&modifier)
+    }
+
+    pub fn set_modifier(&mut self, modifier: TimeModifier, factor: f32) {
+        self.modifiers.insert(modifier, factor);
+    }
+
+    pub fn clear_modifier(&mut self, modifier: TimeModifier) {
+        self.modifiers.remove(&modifier);
+    }
+
+    pub fn update(&mut self, real_delta: Duration) {
+        self.delta = real_delta.mul_f32(self.effective_scale().max(0.0));
+        self.elapsed += self.delta;
+    }
+
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    pub fn elapsed_seconds(&self) -> f32 {
+        self.elapsed.as_secs_f32()
+    }
+}
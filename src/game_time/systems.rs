@@ -0,0 +1,95 @@
+use super::{components::*, resources::*, MAX_TIME_SCALE, MIN_TIME_SCALE};
+use crate::loading::resources::GameAssets;
+use bevy::prelude::*;
+
+pub fn update_game_time(mut game_time: ResMut<GameTime>, time: Res<Time>) {
+    game_time.update(time.delta());
+}
+
+pub fn reset_game_time(mut game_time: ResMut<GameTime>) {
+    game_time.scale = 1.0;
+    game_time.set_enemy_scale(1.0);
+    game_time.clear_modifier(TimeModifier::Pause);
+}
+
+pub fn toggle_pause(keyboard_input: Res<Input<KeyCode>>, mut game_time: ResMut<GameTime>) {
+    if !keyboard_input.just_pressed(KeyCode::P) {
+        return;
+    }
+
+    if game_time.has_modifier(TimeModifier::Pause) {
+        game_time.clear_modifier(TimeModifier::Pause);
+    } else {
+        game_time.set_modifier(TimeModifier::Pause, 0.0);
+    }
+}
+
+pub fn change_time_scale(keyboard_input: Res<Input<KeyCode>>, mut game_time: ResMut<GameTime>) {
+    let factor = if keyboard_input.just_pressed(KeyCode::LBracket) {
+        0.5
+    } else if keyboard_input.just_pressed(KeyCode::RBracket) {
+        2.0
+    } else {
+        return;
+    };
+
+    game_time.scale = (game_time.scale * factor).clamp(MIN_TIME_SCALE, MAX_TIME_SCALE);
+}
+
+pub fn spawn_time_scale_text(mut commands: Commands, game_assets: Res<GameAssets>) {
+    commands
+        .spawn((
+            TimeScaleHud {},
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    size: Size::width(Val::Percent(100.0)),
+                    position: UiRect {
+                        top: Val::Px(10.0),
+                        ..default()
+                    },
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TimeScaleText {},
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: game_assets.font.clone(),
+                        font_size: 32.0,
+                        color: Color::WHITE,
+                    },
+                ),
+            ));
+        });
+}
+
+pub fn despawn_time_scale_text(mut commands: Commands, query: Query<Entity, With<TimeScaleHud>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn update_time_scale_text(
+    mut query: Query<&mut Text, With<TimeScaleText>>,
+    game_time: Res<GameTime>,
+) {
+    let value = if game_time.has_modifier(TimeModifier::Pause) {
+        "Paused".to_string()
+    } else if game_time.scale != 1.0 {
+        format!("x{}", game_time.scale)
+    } else {
+        String::new()
+    };
+
+    for mut text in query.iter_mut() {
+        if text.sections[0].value != value {
+            text.sections[0].value = value.clone();
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use crate::game::states::AppState;
+use resources::*;
+use systems::*;
+
+pub const LEVELS_DIRECTORY: &str = "assets/levels";
+pub const DEFAULT_LEVEL: &str = "default";
+
+pub struct LevelPlugin;
+
+impl Plugin for LevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Level::load_or_default(DEFAULT_LEVEL))
+            .init_resource::<LevelCatalog>()
+            .init_resource::<LevelProgress>()
+            .add_system(scan_levels.in_schedule(OnEnter(AppState::LevelSelect)))
+            .add_system(select_level.in_set(OnUpdate(AppState::LevelSelect)))
+            .add_system(reset_level_progress.in_schedule(OnEnter(AppState::Game)))
+            .add_system(check_win_condition.in_set(OnUpdate(AppState::Game)));
+    }
+}
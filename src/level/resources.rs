@@ -0,0 +1,226 @@
+use super::LEVELS_DIRECTORY;
+use crate::{
+    arena::resources::{Arena, EdgeMode},
+    enemy::{ENEMY_SPAWN_INTERVAL, MAX_NUMBER_OF_ENEMIES},
+    obstacle::components::Shape,
+    settings::resources::WorldSettings,
+    star::{MAX_NUMBER_OF_STARS, STAR_LIFETIME, STAR_SPAWN_INTERVAL},
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::PathBuf};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ArenaDefinition {
+    pub width: f32,
+    pub height: f32,
+    #[serde(default)]
+    pub edges: EdgeMode,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ObstacleDefinition {
+    pub position: Vec2,
+    pub shape: Shape,
+}
+
+impl ObstacleDefinition {
+    pub fn overlaps(&self, point: Vec2, radius: f32) -> bool {
+        self.shape.resolve(point - self.position, radius).is_some()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EnemyDefinition {
+    pub position: Vec2,
+    pub direction: Vec2,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpawnTable {
+    pub enemy_interval: f32,
+    pub max_enemies: usize,
+    pub star_interval: f32,
+    pub max_stars: usize,
+    pub star_lifetime: Option<f32>,
+}
+
+impl Default for SpawnTable {
+    fn default() -> Self {
+        Self {
+            enemy_interval: ENEMY_SPAWN_INTERVAL,
+            max_enemies: MAX_NUMBER_OF_ENEMIES,
+            star_interval: STAR_SPAWN_INTERVAL,
+            max_stars: MAX_NUMBER_OF_STARS,
+            star_lifetime: Some(STAR_LIFETIME),
+        }
+    }
+}
+
+impl SpawnTable {
+    /// Spawn and lifetime timers can't run on durations that aren't positive.
+    pub fn validate(&self) -> Result<(), String> {
+        let durations = [
+            ("enemy_interval", Some(self.enemy_interval)),
+            ("star_interval", Some(self.star_interval)),
+            ("star_lifetime", self.star_lifetime),
+        ];
+        for (name, duration) in durations {
+            let Some(duration) = duration else {
+                continue;
+            };
+            if !(duration.is_finite() && duration > 0.0) {
+                return Err(format!("{} {} must be positive", name, duration));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum WinCondition {
+    #[default]
+    Endless,
+    Score(u32),
+    Survive(f32),
+}
+
+#[derive(Resource, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Level {
+    pub name: String,
+    pub arena: Option<ArenaDefinition>,
+    pub player: Option<Vec2>,
+    pub enemies: Vec<EnemyDefinition>,
+    pub stars: Vec<Vec2>,
+    pub obstacles: Vec<ObstacleDefinition>,
+    pub spawns: SpawnTable,
+    pub win: WinCondition,
+}
+
+impl Level {
+    pub fn path(file: &str) -> PathBuf {
+        PathBuf::from(LEVELS_DIRECTORY).join(format!("{}.ron", file))
+    }
+
+    pub fn load(file: &str) -> Result<Self, String> {
+        let path = Self::path(file);
+        let contents = fs::read_to_string(&path)
+            .map_err(|err| format!("Unable to read level {}: {}", path.display(), err))?;
+        let level: Self = ron::from_str(&contents)
+            .map_err(|err| format!("Invalid level file {}: {}", path.display(), err))?;
+        level
+            .validate()
+            .map_err(|err| format!("Invalid level file {}: {}", path.display(), err))?;
+        Ok(level)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        self.spawns
+            .validate()
+            .map_err(|err| format!("spawns: {}", err))?;
+        for (index, obstacle) in self.obstacles.iter().enumerate() {
+            obstacle
+                .shape
+                .validate()
+                .map_err(|err| format!("obstacle {}: {}", index, err))?;
+        }
+        Ok(())
+    }
+
+    pub fn save(&self, file: &str) -> Result<(), String> {
+        let path = Self::path(file);
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| format!("Unable to serialise level {}: {}", path.display(), err))?;
+        fs::write(&path, contents)
+            .map_err(|err| format!("Unable to write level {}: {}", path.display(), err))
+    }
+
+    pub fn load_or_default(file: &str) -> Self {
+        Self::load(file).unwrap_or_else(|err| {
+            error!("{}", err);
+            Self::default()
+        })
+    }
+
+    pub fn arena(&self, world: &WorldSettings) -> Arena {
+        match &self.arena {
+            Some(definition) => Arena {
+                edges: definition.edges,
+                ..Arena::new(definition.width, definition.height)
+            },
+            None => Arena {
+                edges: world.edges,
+                ..Arena::new(world.width, world.height)
+            },
+        }
+    }
+
+    pub fn player_position(&self, arena: &Arena) -> Vec2 {
+        self.player.unwrap_or_else(|| arena.center())
+    }
+
+    pub fn is_blocked(&self, point: Vec2, radius: f32) -> bool {
+        self.obstacles
+            .iter()
+            .any(|obstacle| obstacle.overlaps(point, radius))
+    }
+}
+
+pub struct LevelEntry {
+    pub file: String,
+    pub name: String,
+}
+
+#[derive(Resource, Default)]
+pub struct LevelCatalog {
+    pub entries: Vec<LevelEntry>,
+    pub selected: usize,
+}
+
+impl LevelCatalog {
+    pub fn scan() -> Self {
+        let mut files: Vec<String> = fs::read_dir(LEVELS_DIRECTORY)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+                    .filter_map(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+                    .collect()
+            })
+            .unwrap_or_else(|err| {
+                error!("Unable to list levels in {}: {}", LEVELS_DIRECTORY, err);
+                Vec::new()
+            });
+        files.sort();
+
+        let entries = files
+            .into_iter()
+            .map(|file| {
+                let name = match Level::load(&file) {
+                    Ok(level) if !level.name.is_empty() => level.name,
+                    Ok(_) => file.clone(),
+                    Err(_) => format!("{} (invalid)", file),
+                };
+                LevelEntry { file, name }
+            })
+            .collect();
+
+        Self {
+            entries,
+            selected: 0,
+        }
+    }
+
+    pub fn selected_file(&self) -> Option<&str> {
+        self.entries
+            .get(self.selected)
+            .map(|entry| entry.file.as_str())
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct LevelProgress {
+    pub elapsed: f32,
+}
@@ -0,0 +1,71 @@
+use super::resources::*;
+use crate::{
+    arena::resources::Arena, game::states::AppState, game_time::resources::GameTime,
+    score::resources::Score, settings::resources::Settings,
+};
+use bevy::prelude::*;
+
+pub fn scan_levels(mut catalog: ResMut<LevelCatalog>) {
+    let selected = catalog.selected;
+    *catalog = LevelCatalog::scan();
+    catalog.selected = selected.min(catalog.entries.len().saturating_sub(1));
+}
+
+pub fn select_level(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut catalog: ResMut<LevelCatalog>,
+    mut level: ResMut<Level>,
+    mut arena: ResMut<Arena>,
+    settings: Res<Settings>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let count = catalog.entries.len();
+    if count == 0 {
+        return;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Up) || keyboard_input.just_pressed(KeyCode::W) {
+        catalog.selected = (catalog.selected + count - 1) % count;
+    }
+    if keyboard_input.just_pressed(KeyCode::Down) || keyboard_input.just_pressed(KeyCode::S) {
+        catalog.selected = (catalog.selected + 1) % count;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Return) || keyboard_input.just_pressed(KeyCode::Space) {
+        let Some(file) = catalog.selected_file() else {
+            return;
+        };
+        match Level::load(file) {
+            Ok(loaded) => {
+                *arena = loaded.arena(&settings.world);
+                *level = loaded;
+                next_state.set(AppState::Game);
+            }
+            Err(err) => error!("{}", err),
+        }
+    }
+}
+
+pub fn reset_level_progress(mut progress: ResMut<LevelProgress>) {
+    progress.elapsed = 0.0;
+}
+
+pub fn check_win_condition(
+    mut progress: ResMut<LevelProgress>,
+    mut next_state: ResMut<NextState<AppState>>,
+    level: Res<Level>,
+    score: Res<Score>,
+    game_time: Res<GameTime>,
+) {
+    progress.elapsed += game_time.delta_seconds();
+
+    let won = match level.win {
+        WinCondition::Endless => false,
+        WinCondition::Score(target) => score.0 >= target,
+        WinCondition::Survive(duration) => progress.elapsed >= duration,
+    };
+
+    if won {
+        next_state.set(AppState::LevelComplete);
+    }
+}
@@ -1,5 +1,25 @@
+pub mod animation;
+pub mod arena;
+pub mod camera;
+pub mod camera_effects;
+pub mod combo;
+pub mod editor;
 pub mod enemy;
 pub mod game;
+pub mod game_time;
+pub mod level;
+pub mod loading;
+pub mod menu;
+pub mod minimap;
+pub mod music;
+pub mod net;
+pub mod obstacle;
+pub mod particle;
 pub mod player;
+pub mod powerup;
+pub mod projectile;
 pub mod score;
+pub mod settings;
+pub mod sound;
 pub mod star;
+pub mod starfield;
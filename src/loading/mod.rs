@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use crate::game::states::AppState;
+use resources::*;
+use systems::*;
+
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameAssets>()
+            .add_system(check_assets_loaded.in_set(OnUpdate(AppState::Loading)));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{
+    animation::components::AnimationClip,
+    enemy::{ENEMY_PULSE, ENEMY_SIZE},
+    music::resources::MusicTrack,
+    player::{PLAYER_DEATH, PLAYER_SIZE},
+    sound::resources::SoundEffect,
+    star::{components::StarTier, STAR_SIZE, STAR_TWINKLE},
+};
+use bevy::prelude::*;
+
+/// Sprite colours for each local player, in `Player::index` order.
+const PLAYER_COLORS: [&str; 4] = ["blue", "green", "yellow", "purple"];
+
+#[derive(Resource)]
+pub struct GameAssets {
+    pub player_textures: Vec<Handle<Image>>,
+    pub player_death_sheets: Vec<Handle<Image>>,
+    pub player_death_atlases: Vec<Handle<TextureAtlas>>,
+    pub enemy_sheet: Handle<Image>,
+    pub enemy_atlas: Handle<TextureAtlas>,
+    pub star_sheet: Handle<Image>,
+    pub star_atlas: Handle<TextureAtlas>,
+    pub rare_star_sheet: Handle<Image>,
+    pub rare_star_atlas: Handle<TextureAtlas>,
+    pub golden_star_sheet: Handle<Image>,
+    pub golden_star_atlas: Handle<TextureAtlas>,
+    pub bounce_sounds: Vec<Handle<AudioSource>>,
+    pub pickup_sounds: Vec<Handle<AudioSource>>,
+    pub explosion_sounds: Vec<Handle<AudioSource>>,
+    pub shot_sounds: Vec<Handle<AudioSource>>,
+    pub menu_music: Handle<AudioSource>,
+    pub game_music: Handle<AudioSource>,
+    pub game_over_music: Handle<AudioSource>,
+    pub font: Handle<Font>,
+}
+
+fn sheet_atlas(
+    atlases: &mut Assets<TextureAtlas>,
+    sheet: &Handle<Image>,
+    size: f32,
+    clip: AnimationClip,
+) -> Handle<TextureAtlas> {
+    atlases.add(TextureAtlas::from_grid(
+        sheet.clone(),
+        Vec2::splat(size),
+        clip.frames,
+        1,
+        None,
+        None,
+    ))
+}
+
+impl FromWorld for GameAssets {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+
+        let player_textures: Vec<Handle<Image>> = PLAYER_COLORS
+            .iter()
+            .map(|color| asset_server.load(format!("sprites/ball_{}_large.png", color)))
+            .collect();
+        let player_death_sheets: Vec<Handle<Image>> = PLAYER_COLORS
+            .iter()
+            .map(|color| asset_server.load(format!("sprites/ball_{}_large_death.png", color)))
+            .collect();
+        let enemy_sheet = asset_server.load("sprites/ball_red_large_sheet.png");
+        let star_sheet = asset_server.load("sprites/star_sheet.png");
+        let rare_star_sheet = asset_server.load("sprites/star_rare_sheet.png");
+        let golden_star_sheet = asset_server.load("sprites/star_golden_sheet.png");
+        let bounce_sounds = vec![
+            asset_server.load("audio/pluck_000.ogg"),
+            asset_server.load("audio/pluck_001.ogg"),
+        ];
+        let pickup_sounds = vec![asset_server.load("audio/laser_large_000.ogg")];
+        let explosion_sounds = vec![asset_server.load("audio/explosion_crunch_000.ogg")];
+        let shot_sounds = vec![asset_server.load("audio/laser_large_000.ogg")];
+        let menu_music = asset_server.load("audio/music/menu.wav");
+        let game_music = asset_server.load("audio/music/game.wav");
+        let game_over_music = asset_server.load("audio/music/game_over.wav");
+        let font = asset_server.load("fonts/DejaVuSans.ttf");
+
+        let mut atlases = world.resource_mut::<Assets<TextureAtlas>>();
+        Self {
+            player_death_atlases: player_death_sheets
+                .iter()
+                .map(|sheet| sheet_atlas(&mut atlases, sheet, PLAYER_SIZE, PLAYER_DEATH))
+                .collect(),
+            enemy_atlas: sheet_atlas(&mut atlases, &enemy_sheet, ENEMY_SIZE, ENEMY_PULSE),
+            star_atlas: sheet_atlas(&mut atlases, &star_sheet, STAR_SIZE, STAR_TWINKLE),
+            rare_star_atlas: sheet_atlas(&mut atlases, &rare_star_sheet, STAR_SIZE, STAR_TWINKLE),
+            golden_star_atlas: sheet_atlas(
+                &mut atlases,
+                &golden_star_sheet,
+                STAR_SIZE,
+                STAR_TWINKLE,
+            ),
+            player_textures,
+            player_death_sheets,
+            enemy_sheet,
+            star_sheet,
+            rare_star_sheet,
+            golden_star_sheet,
+            bounce_sounds,
+            pickup_sounds,
+            explosion_sounds,
+            shot_sounds,
+            menu_music,
+            game_music,
+            game_over_music,
+            font,
+        }
+    }
+}
+
+impl GameAssets {
+    pub fn sound_effect(&self, effect: SoundEffect) -> &[Handle<AudioSource>] {
+        match effect {
+            SoundEffect::Bounce => &self.bounce_sounds,
+            SoundEffect::Pickup => &self.pickup_sounds,
+            SoundEffect::Explosion => &self.explosion_sounds,
+            SoundEffect::Shot => &self.shot_sounds,
+        }
+    }
+
+    pub fn player_texture(&self, player: usize) -> &Handle<Image> {
+        &self.player_textures[player % self.player_textures.len()]
+    }
+
+    pub fn player_death_atlas(&self, player: usize) -> &Handle<TextureAtlas> {
+        &self.player_death_atlases[player % self.player_death_atlases.len()]
+    }
+
+    pub fn star_atlas(&self, tier: StarTier) -> &Handle<TextureAtlas> {
+        match tier {
+            StarTier::Common => &self.star_atlas,
+            StarTier::Rare => &self.rare_star_atlas,
+            StarTier::Golden => &self.golden_star_atlas,
+        }
+    }
+
+    pub fn music(&self, track: MusicTrack) -> &Handle<AudioSource> {
+        match track {
+            MusicTrack::Menu => &self.menu_music,
+            MusicTrack::Game => &self.game_music,
+            MusicTrack::GameOver => &self.game_over_music,
+        }
+    }
+
+    pub fn handles(&self) -> Vec<HandleUntyped> {
+        let textures = self
+            .player_textures
+            .iter()
+            .chain(self.player_death_sheets.iter())
+            .chain([
+                &self.enemy_sheet,
+                &self.star_sheet,
+                &self.rare_star_sheet,
+                &self.golden_star_sheet,
+            ])
+            .map(|handle| handle.clone_untyped());
+
+        let sounds = self
+            .bounce_sounds
+            .iter()
+            .chain(self.pickup_sounds.iter())
+            .chain(self.explosion_sounds.iter())
+            .chain(self.shot_sounds.iter())
+            .chain([&self.menu_music, &self.game_music, &self.game_over_music])
+            .map(|handle| handle.clone_untyped());
+
+        textures
+            .chain(sounds)
+            .chain([self.font.clone_untyped()])
+            .collect()
+    }
+}
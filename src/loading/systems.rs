@@ -0,0 +1,32 @@
+use super::resources::*;
+use crate::game::states::AppState;
+use bevy::{app::AppExit, asset::LoadState, prelude::*};
+
+pub fn check_assets_loaded(
+    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let handles = game_assets.handles();
+
+    match asset_server.get_group_load_state(handles.iter().map(|handle| handle.id())) {
+        LoadState::Loaded => next_state.set(AppState::MainMenu),
+        LoadState::Failed => {
+            for handle in handles.iter() {
+                if asset_server.get_load_state(handle) == LoadState::Failed {
+                    let path = asset_server
+                        .get_handle_path(handle)
+                        .map(|path| path.path().display().to_string())
+                        .unwrap_or_else(|| String::from("<unknown>"));
+                    error!(
+                        "Failed to load asset \"{}\": check that the file exists",
+                        path
+                    );
+                }
+            }
+            exit.send(AppExit);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct MainMenuScreen {}
+
+#[derive(Component)]
+pub struct PlayerCountText {}
+
+#[derive(Component)]
+pub struct LevelSelectScreen {}
+
+#[derive(Component)]
+pub struct LevelList {}
+
+#[derive(Component)]
+pub struct GameOverScreen {}
+
+#[derive(Component)]
+pub struct LevelCompleteScreen {}
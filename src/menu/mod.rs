@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod systems;
+
+use crate::game::states::AppState;
+use systems::*;
+
+pub const LEVEL_OPTION_COLOR: Color = Color::rgb(0.7, 0.7, 0.7);
+pub const SELECTED_LEVEL_OPTION_COLOR: Color = Color::rgb(1.0, 0.85, 0.3);
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(spawn_main_menu.in_schedule(OnEnter(AppState::MainMenu)))
+            .add_system(despawn_main_menu.in_schedule(OnExit(AppState::MainMenu)))
+            .add_systems(
+                (
+                    open_level_select,
+                    choose_player_count,
+                    update_player_count_text,
+                )
+                    .in_set(OnUpdate(AppState::MainMenu)),
+            )
+            .add_system(spawn_level_select_screen.in_schedule(OnEnter(AppState::LevelSelect)))
+            .add_system(despawn_level_select_screen.in_schedule(OnExit(AppState::LevelSelect)))
+            .add_systems(
+                (update_level_list, leave_level_select).in_set(OnUpdate(AppState::LevelSelect)),
+            )
+            .add_system(spawn_game_over_screen.in_schedule(OnEnter(AppState::GameOver)))
+            .add_system(despawn_game_over_screen.in_schedule(OnExit(AppState::GameOver)))
+            .add_system(restart_game.in_set(OnUpdate(AppState::GameOver)))
+            .add_system(spawn_level_complete_screen.in_schedule(OnEnter(AppState::LevelComplete)))
+            .add_system(despawn_level_complete_screen.in_schedule(OnExit(AppState::LevelComplete)))
+            .add_system(continue_after_level.in_set(OnUpdate(AppState::LevelComplete)));
+    }
+}
@@ -0,0 +1,315 @@
+use super::components::*;
+use super::{LEVEL_OPTION_COLOR, SELECTED_LEVEL_OPTION_COLOR};
+use crate::{
+    game::states::AppState,
+    level::resources::LevelCatalog,
+    loading::resources::GameAssets,
+    player::MAX_PLAYERS,
+    score::resources::{PlayerScores, Score},
+    settings::resources::Settings,
+};
+use bevy::prelude::*;
+
+fn spawn_screen(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    marker: impl Component,
+    lines: &[(&str, f32)],
+) -> Entity {
+    commands
+        .spawn((
+            marker,
+            NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    gap: Size::height(Val::Px(16.0)),
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            for (text, font_size) in lines {
+                parent.spawn(TextBundle::from_section(
+                    *text,
+                    TextStyle {
+                        font: game_assets.font.clone(),
+                        font_size: *font_size,
+                        color: Color::WHITE,
+                    },
+                ));
+            }
+        })
+        .id()
+}
+
+/// Per-player breakdown for the end screens, empty when playing alone.
+fn player_scores_line(player_scores: &PlayerScores) -> String {
+    if player_scores.0.len() <= 1 {
+        return String::new();
+    }
+
+    player_scores
+        .0
+        .iter()
+        .enumerate()
+        .map(|(index, points)| format!("P{}: {}", index + 1, points))
+        .collect::<Vec<_>>()
+        .join("   ")
+}
+
+fn despawn_screen<T: Component>(commands: &mut Commands, query: &Query<Entity, With<T>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn spawn_main_menu(mut commands: Commands, game_assets: Res<GameAssets>) {
+    let screen = spawn_screen(
+        &mut commands,
+        &game_assets,
+        MainMenuScreen {},
+        &[("ECS", 96.0), ("Press Space to start", 32.0)],
+    );
+    commands.entity(screen).with_children(|parent| {
+        parent.spawn((
+            PlayerCountText {},
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font: game_assets.font.clone(),
+                    font_size: 24.0,
+                    color: Color::WHITE,
+                },
+            ),
+        ));
+    });
+}
+
+pub fn choose_player_count(keyboard_input: Res<Input<KeyCode>>, mut settings: ResMut<Settings>) {
+    let keys = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4];
+    let Some(players) = keys
+        .iter()
+        .position(|key| keyboard_input.just_pressed(*key))
+        .map(|index| index + 1)
+    else {
+        return;
+    };
+
+    if settings.controls.players != players {
+        settings.controls.players = players;
+    }
+}
+
+pub fn update_player_count_text(
+    mut query: Query<(&mut Text, Ref<PlayerCountText>)>,
+    settings: Res<Settings>,
+) {
+    for (mut text, marker) in query.iter_mut() {
+        if !settings.is_changed() && !marker.is_added() {
+            continue;
+        }
+        text.sections[0].value = format!(
+            "Players: {} (press 1 to {} to change)",
+            settings.controls.players(),
+            MAX_PLAYERS
+        );
+    }
+}
+
+pub fn despawn_main_menu(mut commands: Commands, query: Query<Entity, With<MainMenuScreen>>) {
+    despawn_screen(&mut commands, &query);
+}
+
+pub fn open_level_select(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Space) || keyboard_input.just_pressed(KeyCode::Return) {
+        next_state.set(AppState::LevelSelect);
+    }
+}
+
+pub fn spawn_level_select_screen(mut commands: Commands, game_assets: Res<GameAssets>) {
+    spawn_screen(
+        &mut commands,
+        &game_assets,
+        LevelSelectScreen {},
+        &[
+            ("Select a level", 64.0),
+            (
+                "Up / Down to choose, Enter to play, Backspace to go back",
+                24.0,
+            ),
+            ("E to edit the selected level, N to create a new one", 24.0),
+        ],
+    );
+}
+
+pub fn despawn_level_select_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<LevelSelectScreen>>,
+) {
+    despawn_screen(&mut commands, &query);
+}
+
+pub fn update_level_list(
+    mut commands: Commands,
+    screen_query: Query<Entity, With<LevelSelectScreen>>,
+    list_query: Query<Entity, With<LevelList>>,
+    catalog: Res<LevelCatalog>,
+    game_assets: Res<GameAssets>,
+) {
+    if !catalog.is_changed() {
+        return;
+    }
+    let Ok(screen) = screen_query.get_single() else {
+        return;
+    };
+
+    for entity in list_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let list = commands
+        .spawn((
+            LevelList {},
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    gap: Size::height(Val::Px(8.0)),
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            if catalog.entries.is_empty() {
+                parent.spawn(TextBundle::from_section(
+                    "No levels found",
+                    TextStyle {
+                        font: game_assets.font.clone(),
+                        font_size: 32.0,
+                        color: LEVEL_OPTION_COLOR,
+                    },
+                ));
+            }
+
+            for (index, entry) in catalog.entries.iter().enumerate() {
+                let selected = index == catalog.selected;
+                parent.spawn(TextBundle::from_section(
+                    if selected {
+                        format!("> {} <", entry.name)
+                    } else {
+                        entry.name.clone()
+                    },
+                    TextStyle {
+                        font: game_assets.font.clone(),
+                        font_size: 32.0,
+                        color: if selected {
+                            SELECTED_LEVEL_OPTION_COLOR
+                        } else {
+                            LEVEL_OPTION_COLOR
+                        },
+                    },
+                ));
+            }
+        })
+        .id();
+    commands.entity(screen).insert_children(1, &[list]);
+}
+
+pub fn leave_level_select(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Back) {
+        next_state.set(AppState::MainMenu);
+    }
+}
+
+pub fn spawn_game_over_screen(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    score: Res<Score>,
+    player_scores: Res<PlayerScores>,
+) {
+    let score = format!("Score: {}", score.0);
+    let player_scores = player_scores_line(&player_scores);
+    spawn_screen(
+        &mut commands,
+        &game_assets,
+        GameOverScreen {},
+        &[
+            ("Game Over", 80.0),
+            (&score, 40.0),
+            (&player_scores, 28.0),
+            ("Press Enter to play again or Backspace for the menu", 24.0),
+        ],
+    );
+}
+
+pub fn despawn_game_over_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<GameOverScreen>>,
+) {
+    despawn_screen(&mut commands, &query);
+}
+
+pub fn spawn_level_complete_screen(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    score: Res<Score>,
+    player_scores: Res<PlayerScores>,
+) {
+    let score = format!("Score: {}", score.0);
+    let player_scores = player_scores_line(&player_scores);
+    spawn_screen(
+        &mut commands,
+        &game_assets,
+        LevelCompleteScreen {},
+        &[
+            ("Level Complete", 80.0),
+            (&score, 40.0),
+            (&player_scores, 28.0),
+            (
+                "Press Enter to choose another level or Backspace for the menu",
+                24.0,
+            ),
+        ],
+    );
+}
+
+pub fn despawn_level_complete_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<LevelCompleteScreen>>,
+) {
+    despawn_screen(&mut commands, &query);
+}
+
+pub fn continue_after_level(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    // Not Space, which players may still be holding down to shoot when the round ends.
+    if keyboard_input.just_pressed(KeyCode::Return) {
+        next_state.set(AppState::LevelSelect);
+    } else if keyboard_input.just_pressed(KeyCode::Back) {
+        next_state.set(AppState::MainMenu);
+    }
+}
+
+pub fn restart_game(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Return) {
+        next_state.set(AppState::Game);
+    } else if keyboard_input.just_pressed(KeyCode::Back) {
+        next_state.set(AppState::MainMenu);
+    }
+}
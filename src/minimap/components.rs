@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct Minimap {}
+
+#[derive(Component)]
+pub struct MinimapDot {}
+
+#[derive(Component)]
+pub struct MinimapView {}
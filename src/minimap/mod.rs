@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod systems;
+
+use crate::game::states::AppState;
+use systems::*;
+
+pub const MINIMAP_WIDTH: f32 = 200.0;
+pub const MINIMAP_MARGIN: f32 = 10.0;
+pub const MINIMAP_DOT_SIZE: f32 = 4.0;
+pub const MINIMAP_BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.6);
+pub const MINIMAP_PLAYER_COLOR: Color = Color::rgb(0.3, 0.6, 1.0);
+pub const MINIMAP_ENEMY_COLOR: Color = Color::rgb(1.0, 0.3, 0.3);
+pub const MINIMAP_STAR_COLOR: Color = Color::rgb(1.0, 0.9, 0.3);
+pub const MINIMAP_VIEW_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.15);
+
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(spawn_minimap.in_schedule(OnEnter(AppState::Game)))
+            .add_system(despawn_minimap.in_schedule(OnExit(AppState::Game)))
+            .add_system(update_minimap.in_set(OnUpdate(AppState::Game)));
+    }
+}
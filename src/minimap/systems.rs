@@ -0,0 +1,138 @@
+use super::{
+    components::*, MINIMAP_BACKGROUND_COLOR, MINIMAP_DOT_SIZE, MINIMAP_ENEMY_COLOR, MINIMAP_MARGIN,
+    MINIMAP_PLAYER_COLOR, MINIMAP_STAR_COLOR, MINIMAP_VIEW_COLOR, MINIMAP_WIDTH,
+};
+use crate::{
+    arena::resources::Arena, enemy::components::Enemy, game::components::MainCamera,
+    player::components::Player, star::components::Star,
+};
+use bevy::prelude::*;
+
+fn minimap_scale(arena: &Arena) -> f32 {
+    MINIMAP_WIDTH / arena.width
+}
+
+fn dot_style(position: Vec2, size: Vec2) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        position: UiRect {
+            left: Val::Px(position.x - size.x * 0.5),
+            bottom: Val::Px(position.y - size.y * 0.5),
+            ..default()
+        },
+        size: Size::new(Val::Px(size.x), Val::Px(size.y)),
+        ..default()
+    }
+}
+
+pub fn spawn_minimap(mut commands: Commands, arena: Res<Arena>) {
+    if !arena.is_scrolling() {
+        return;
+    }
+
+    let scale = minimap_scale(&arena);
+    commands
+        .spawn((
+            Minimap {},
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    position: UiRect {
+                        right: Val::Px(MINIMAP_MARGIN),
+                        top: Val::Px(MINIMAP_MARGIN),
+                        ..default()
+                    },
+                    size: Size::new(Val::Px(arena.width * scale), Val::Px(arena.height * scale)),
+                    ..default()
+                },
+                background_color: MINIMAP_BACKGROUND_COLOR.into(),
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                MinimapView {},
+                NodeBundle {
+                    style: dot_style(arena.center() * scale, arena.view_size() * scale),
+                    background_color: MINIMAP_VIEW_COLOR.into(),
+                    ..default()
+                },
+            ));
+        });
+}
+
+pub fn despawn_minimap(mut commands: Commands, query: Query<Entity, With<Minimap>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_minimap(
+    mut commands: Commands,
+    minimap_query: Query<Entity, With<Minimap>>,
+    mut dot_query: Query<(Entity, &mut Style, &mut BackgroundColor), With<MinimapDot>>,
+    mut view_query: Query<&mut Style, (With<MinimapView>, Without<MinimapDot>)>,
+    camera_query: Query<&Transform, With<MainCamera>>,
+    player_query: Query<&Transform, With<Player>>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+    star_query: Query<&Transform, With<Star>>,
+    arena: Res<Arena>,
+) {
+    let Ok(minimap) = minimap_query.get_single() else {
+        return;
+    };
+    let scale = minimap_scale(&arena);
+    let dot_size = Vec2::splat(MINIMAP_DOT_SIZE);
+
+    if let (Ok(mut view_style), Ok(camera_transform)) =
+        (view_query.get_single_mut(), camera_query.get_single())
+    {
+        *view_style = dot_style(
+            camera_transform.translation.truncate() * scale,
+            arena.view_size() * scale,
+        );
+    }
+
+    let markers = star_query
+        .iter()
+        .map(|transform| (transform, MINIMAP_STAR_COLOR))
+        .chain(
+            enemy_query
+                .iter()
+                .map(|transform| (transform, MINIMAP_ENEMY_COLOR)),
+        )
+        .chain(
+            player_query
+                .iter()
+                .map(|transform| (transform, MINIMAP_PLAYER_COLOR)),
+        )
+        .map(|(transform, color)| (transform.translation.truncate() * scale, color));
+
+    let mut dots = dot_query.iter_mut();
+    for (position, color) in markers {
+        match dots.next() {
+            Some((_, mut style, mut background_color)) => {
+                *style = dot_style(position, dot_size);
+                *background_color = color.into();
+            }
+            None => {
+                let dot = commands
+                    .spawn((
+                        MinimapDot {},
+                        NodeBundle {
+                            style: dot_style(position, dot_size),
+                            background_color: color.into(),
+                            ..default()
+                        },
+                    ))
+                    .id();
+                commands.entity(minimap).add_child(dot);
+            }
+        }
+    }
+
+    for (entity, _, _) in dots {
+        commands.entity(entity).despawn_recursive();
+    }
+}
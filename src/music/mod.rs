@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+pub const CROSSFADE_DURATION: f32 = 1.5;
+pub const DUCK_LEVEL: f32 = 0.25;
+pub const DUCK_DURATION: f32 = 1.2;
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MusicPlayer>()
+            .add_system(change_music_track)
+            .add_system(duck_music_on_explosion)
+            .add_system(
+                update_music_volume
+                    .after(change_music_track)
+                    .after(duck_music_on_explosion),
+            );
+    }
+}
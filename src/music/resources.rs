@@ -0,0 +1,35 @@
+use crate::game::states::AppState;
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MusicTrack {
+    Menu,
+    Game,
+    GameOver,
+}
+
+impl MusicTrack {
+    pub fn for_state(state: AppState) -> Option<Self> {
+        match state {
+            AppState::Loading => None,
+            AppState::MainMenu | AppState::LevelSelect | AppState::Editor => Some(MusicTrack::Menu),
+            AppState::Game | AppState::Online => Some(MusicTrack::Game),
+            AppState::GameOver => Some(MusicTrack::GameOver),
+            AppState::LevelComplete => Some(MusicTrack::Menu),
+        }
+    }
+}
+
+pub struct MusicVoice {
+    pub track: MusicTrack,
+    pub sink: Handle<AudioSink>,
+    pub fade: f32,
+    pub fading_in: bool,
+}
+
+#[derive(Resource, Default)]
+pub struct MusicPlayer {
+    pub current: Option<MusicTrack>,
+    pub voices: Vec<MusicVoice>,
+    pub duck: f32,
+}
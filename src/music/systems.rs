@@ -0,0 +1,81 @@
+use super::{resources::*, CROSSFADE_DURATION, DUCK_DURATION, DUCK_LEVEL};
+use crate::{
+    game::states::AppState,
+    loading::resources::GameAssets,
+    settings::resources::Settings,
+    sound::{events::PlaySoundEffect, resources::SoundEffect},
+};
+use bevy::prelude::*;
+
+pub fn change_music_track(
+    state: Res<State<AppState>>,
+    mut music_player: ResMut<MusicPlayer>,
+    audio: Res<Audio>,
+    audio_sinks: Res<Assets<AudioSink>>,
+    game_assets: Res<GameAssets>,
+) {
+    let track = MusicTrack::for_state(state.0);
+    if track == music_player.current {
+        return;
+    }
+    music_player.current = track;
+
+    for voice in music_player.voices.iter_mut() {
+        voice.fading_in = false;
+    }
+
+    if let Some(track) = track {
+        let sink = audio.play_with_settings(
+            game_assets.music(track).clone(),
+            PlaybackSettings::LOOP.with_volume(0.0),
+        );
+        music_player.voices.push(MusicVoice {
+            track,
+            sink: audio_sinks.get_handle(sink),
+            fade: 0.0,
+            fading_in: true,
+        });
+    }
+}
+
+pub fn duck_music_on_explosion(
+    mut play_sound_event_reader: EventReader<PlaySoundEffect>,
+    mut music_player: ResMut<MusicPlayer>,
+) {
+    if play_sound_event_reader
+        .iter()
+        .any(|event| event.effect == SoundEffect::Explosion)
+    {
+        music_player.duck = 1.0;
+    }
+}
+
+pub fn update_music_volume(
+    mut music_player: ResMut<MusicPlayer>,
+    audio_sinks: Res<Assets<AudioSink>>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+) {
+    let step = time.delta_seconds() / CROSSFADE_DURATION;
+    music_player.duck = (music_player.duck - time.delta_seconds() / DUCK_DURATION).max(0.0);
+
+    let level = settings.audio.music_level() * (1.0 - music_player.duck * (1.0 - DUCK_LEVEL));
+    for voice in music_player.voices.iter_mut() {
+        voice.fade = if voice.fading_in {
+            (voice.fade + step).min(1.0)
+        } else {
+            (voice.fade - step).max(0.0)
+        };
+
+        if let Some(sink) = audio_sinks.get(&voice.sink) {
+            sink.set_volume(voice.fade * level);
+            if !voice.fading_in && voice.fade <= 0.0 {
+                sink.stop();
+            }
+        }
+    }
+
+    music_player
+        .voices
+        .retain(|voice| voice.fading_in || voice.fade > 0.0);
+}
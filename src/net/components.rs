@@ -0,0 +1,42 @@
+use crate::{powerup::components::PowerUpKind, star::components::StarTier};
+use bevy::prelude::*;
+use std::net::SocketAddr;
+
+/// Player driven by inputs arriving from a client rather than by local `Controls`.
+#[derive(Component)]
+pub struct RemotePlayer {
+    pub address: SocketAddr,
+}
+
+/// Client-side stand-in for an entity simulated by the host, moved along its last known
+/// velocity between snapshots.
+#[derive(Component)]
+pub struct Replicated {
+    pub id: u64,
+    pub velocity: Vec2,
+}
+
+/// What a replicated entity is drawn as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplicatedKind {
+    Player(usize),
+    Enemy,
+    Star(StarTier),
+    Projectile,
+    PowerUp(PowerUpKind),
+}
+
+impl ReplicatedKind {
+    /// Depth matching the locally simulated entity of the same kind.
+    pub fn z(&self) -> f32 {
+        match self {
+            ReplicatedKind::Player(_) | ReplicatedKind::Enemy => 0.0,
+            ReplicatedKind::Star(_) => -1.0,
+            ReplicatedKind::PowerUp(_) => -0.5,
+            ReplicatedKind::Projectile => 0.5,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct NetStatusText {}
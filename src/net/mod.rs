@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod protocol;
+pub mod resources;
+mod systems;
+
+use crate::{game::states::AppState, player::PlayerInputSet};
+use resources::*;
+use systems::*;
+
+pub const DEFAULT_PORT: u16 = 5757;
+pub const SNAPSHOT_INTERVAL: f32 = 0.05;
+pub const HELLO_INTERVAL: f32 = 1.0;
+pub const CONNECTION_TIMEOUT: f32 = 5.0;
+pub const MAX_PENDING_INPUTS: usize = 256;
+pub const CORRECTION_SNAP_DISTANCE: f32 = 96.0;
+pub const CORRECTION_RATE: f32 = 10.0;
+
+pub struct NetPlugin;
+
+impl Plugin for NetPlugin {
+    fn build(&self, app: &mut App) {
+        let role = NetRole::from_args(std::env::args().skip(1)).unwrap_or_else(|err| {
+            error!("{}", err);
+            NetRole::Offline
+        });
+
+        app.insert_resource(role)
+            .add_startup_system(open_socket)
+            .add_system(spawn_net_status_text.in_schedule(OnExit(AppState::Loading)))
+            .add_system(update_net_status_text)
+            .add_systems(
+                (
+                    receive_client_messages.before(PlayerInputSet),
+                    drop_silent_clients,
+                    broadcast_snapshot,
+                )
+                    .distributive_run_if(resource_exists::<NetHost>())
+                    .in_set(OnUpdate(AppState::Game)),
+            )
+            .add_system(
+                end_round
+                    .run_if(resource_exists::<NetHost>())
+                    .in_schedule(OnExit(AppState::Game)),
+            )
+            .add_systems(
+                (toggle_joining, join_host)
+                    .chain()
+                    .distributive_run_if(resource_exists::<NetClient>())
+                    .in_set(OnUpdate(AppState::MainMenu)),
+            )
+            .add_systems(
+                (receive_server_messages, apply_snapshot, reconcile_player)
+                    .chain()
+                    .before(PlayerInputSet)
+                    .in_set(OnUpdate(AppState::Online)),
+            )
+            .add_systems(
+                (
+                    send_input.after(PlayerInputSet),
+                    extrapolate_replicated,
+                    leave_host,
+                )
+                    .in_set(OnUpdate(AppState::Online)),
+            )
+            .add_system(despawn_replicated.in_schedule(OnExit(AppState::Online)));
+    }
+}
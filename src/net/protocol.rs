@@ -0,0 +1,192 @@
+use crate::{
+    level::resources::Level,
+    player::components::PlayerInput,
+    powerup::components::PowerUpKind,
+    settings::resources::{ControlSettings, WorldSettings},
+    star::components::StarTier,
+};
+use bevy::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    io::ErrorKind,
+    net::{SocketAddr, UdpSocket},
+};
+
+/// Bumped whenever a message changes shape, so mismatched builds refuse each other.
+pub const PROTOCOL_VERSION: u16 = 1;
+pub const MAX_PACKET_SIZE: usize = 65_507;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ClientMessage {
+    Hello { version: u16 },
+    Input { sequence: u32, input: PlayerInput },
+    Bye,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ServerMessage {
+    Welcome {
+        player: usize,
+        level: Level,
+        world: WorldSettings,
+        controls: ControlSettings,
+    },
+    Rejected(String),
+    Snapshot(Snapshot),
+    RoundOver {
+        score: u32,
+    },
+}
+
+/// Everything a client draws, as simulated by the host on one of its frames.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub tick: u32,
+    /// Sequence of the last input the host applied for the receiving client.
+    pub ack: u32,
+    pub players: Vec<PlayerState>,
+    pub enemies: Vec<EntityState>,
+    pub stars: Vec<StarState>,
+    pub projectiles: Vec<EntityState>,
+    pub power_ups: Vec<PowerUpState>,
+    pub score: u32,
+    pub player_scores: Vec<u32>,
+    /// How fast the host's game clock runs, so clients predict at the same pace.
+    pub time_scale: f32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlayerState {
+    pub id: u64,
+    pub index: usize,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    /// Power-ups the player is holding, some of which change how it moves.
+    pub effects: Vec<PowerUpKind>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EntityState {
+    pub id: u64,
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StarState {
+    pub id: u64,
+    pub position: Vec2,
+    pub tier: StarTier,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PowerUpState {
+    pub id: u64,
+    pub position: Vec2,
+    pub kind: PowerUpKind,
+}
+
+pub fn send<T: Serialize>(socket: &UdpSocket, address: SocketAddr, message: &T) {
+    let result = bincode::serialize(message)
+        .map_err(|err| err.to_string())
+        .and_then(|bytes| {
+            socket
+                .send_to(&bytes, address)
+                .map_err(|err| err.to_string())
+        });
+    if let Err(err) = result {
+        warn!("Unable to send to {}: {}", address, err);
+    }
+}
+
+/// Drains every datagram waiting on the non-blocking socket, skipping any that don't decode.
+pub fn receive<T: DeserializeOwned>(socket: &UdpSocket) -> Vec<(SocketAddr, T)> {
+    let mut buffer = [0; MAX_PACKET_SIZE];
+    let mut messages = Vec::new();
+
+    loop {
+        match socket.recv_from(&mut buffer) {
+            Ok((length, address)) => match bincode::deserialize(&buffer[..length]) {
+                Ok(message) => messages.push((address, message)),
+                Err(err) => warn!("Dropped malformed packet from {}: {}", address, err),
+            },
+            Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+            // A client going away can surface as a reset on the next read, which is harmless.
+            Err(err) if err.kind() == ErrorKind::ConnectionReset => continue,
+            Err(err) => {
+                warn!("Unable to receive: {}", err);
+                break;
+            }
+        }
+    }
+
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        thread,
+        time::{Duration, Instant},
+    };
+
+    fn loopback() -> UdpSocket {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket.set_nonblocking(true).unwrap();
+        socket
+    }
+
+    /// Polls until something arrives, since loopback delivery isn't instant.
+    fn receive_some<T: DeserializeOwned>(socket: &UdpSocket) -> Vec<(SocketAddr, T)> {
+        let deadline = Instant::now() + Duration::from_secs(2);
+        loop {
+            let messages = receive(socket);
+            if !messages.is_empty() || Instant::now() > deadline {
+                return messages;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn message_round_trip() {
+        let client = loopback();
+        let host = loopback();
+        let input = PlayerInput {
+            movement: Vec2::new(0.5, -1.0),
+            dash: true,
+            fire: false,
+            aim: Some(Vec2::X),
+        };
+
+        send(
+            &client,
+            host.local_addr().unwrap(),
+            &ClientMessage::Input { sequence: 7, input },
+        );
+        let messages = receive_some::<ClientMessage>(&host);
+
+        assert_eq!(messages.len(), 1);
+        let (address, message) = &messages[0];
+        assert_eq!(*address, client.local_addr().unwrap());
+        assert!(matches!(
+            message,
+            ClientMessage::Input { sequence: 7, input: received } if *received == input
+        ));
+    }
+
+    #[test]
+    fn malformed_packets_are_dropped() {
+        let client = loopback();
+        let host = loopback();
+        let address = host.local_addr().unwrap();
+
+        client.send_to(&[0xFF; 3], address).unwrap();
+        send(&client, address, &ClientMessage::Bye);
+        let messages = receive_some::<ClientMessage>(&host);
+
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(messages[0].1, ClientMessage::Bye));
+    }
+}
@@ -0,0 +1,186 @@
+use super::{protocol::*, DEFAULT_PORT, HELLO_INTERVAL, SNAPSHOT_INTERVAL};
+use crate::{player::components::PlayerInput, settings::resources::ControlSettings};
+use bevy::{prelude::*, utils::HashMap};
+use std::{
+    collections::VecDeque,
+    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+};
+
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NetRole {
+    #[default]
+    Offline,
+    Host {
+        port: u16,
+    },
+    Client {
+        host: SocketAddr,
+    },
+}
+
+impl NetRole {
+    /// Reads `--host [port]` or `--join <address[:port]>` from the command line.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--host" => {
+                    let port = match args.next() {
+                        Some(port) => port.parse().map_err(|_| format!("Invalid port {}", port))?,
+                        None => DEFAULT_PORT,
+                    };
+                    return Ok(NetRole::Host { port });
+                }
+                "--join" => {
+                    let address = args
+                        .next()
+                        .ok_or_else(|| "--join needs the host's address".to_string())?;
+                    let address = if address.contains(':') {
+                        address
+                    } else {
+                        format!("{}:{}", address, DEFAULT_PORT)
+                    };
+                    let host = address
+                        .to_socket_addrs()
+                        .ok()
+                        .and_then(|mut addresses| addresses.next())
+                        .ok_or_else(|| format!("Unable to resolve {}", address))?;
+                    return Ok(NetRole::Client { host });
+                }
+                _ => {}
+            }
+        }
+        Ok(NetRole::Offline)
+    }
+}
+
+pub struct ClientConnection {
+    pub player: usize,
+    pub entity: Entity,
+    pub last_sequence: u32,
+    pub last_heard: f32,
+}
+
+/// Socket and connected clients of an instance running the authoritative simulation.
+#[derive(Resource)]
+pub struct NetHost {
+    pub socket: UdpSocket,
+    pub port: u16,
+    pub clients: HashMap<SocketAddr, ClientConnection>,
+    pub snapshot_timer: Timer,
+    pub tick: u32,
+}
+
+impl NetHost {
+    pub fn new(socket: UdpSocket, port: u16) -> Self {
+        Self {
+            socket,
+            port,
+            clients: HashMap::default(),
+            snapshot_timer: Timer::from_seconds(SNAPSHOT_INTERVAL, TimerMode::Repeating),
+            tick: 0,
+        }
+    }
+
+    pub fn send(&self, address: SocketAddr, message: &ServerMessage) {
+        send(&self.socket, address, message);
+    }
+}
+
+/// An input the local player has already acted on but the host hasn't acknowledged yet.
+pub struct PendingInput {
+    pub sequence: u32,
+    pub input: PlayerInput,
+    pub delta: f32,
+}
+
+/// Connection to a host, along with what is needed to predict the local player ahead of it.
+#[derive(Resource)]
+pub struct NetClient {
+    pub socket: UdpSocket,
+    pub host: SocketAddr,
+    pub joining: bool,
+    pub hello_timer: Timer,
+    /// `Player::index` the host gave us, once welcomed.
+    pub player: Option<usize>,
+    pub sequence: u32,
+    pub pending: VecDeque<PendingInput>,
+    pub snapshot: Option<Snapshot>,
+    pub last_tick: u32,
+    pub last_heard: f32,
+    /// Host entity ids mapped to the local entities standing in for them.
+    pub replicated: HashMap<u64, Entity>,
+    /// The host's handling, which the local player is predicted with while online.
+    pub controls: Option<ControlSettings>,
+}
+
+impl NetClient {
+    pub fn new(socket: UdpSocket, host: SocketAddr) -> Self {
+        let mut hello_timer = Timer::from_seconds(HELLO_INTERVAL, TimerMode::Repeating);
+        hello_timer.set_elapsed(hello_timer.duration());
+
+        Self {
+            socket,
+            host,
+            joining: true,
+            hello_timer,
+            player: None,
+            sequence: 0,
+            pending: VecDeque::new(),
+            snapshot: None,
+            last_tick: 0,
+            last_heard: 0.0,
+            replicated: HashMap::default(),
+            controls: None,
+        }
+    }
+
+    pub fn send(&self, message: &ClientMessage) {
+        send(&self.socket, self.host, message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<NetRole, String> {
+        NetRole::from_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn no_role_is_offline() {
+        assert_eq!(parse(&[]), Ok(NetRole::Offline));
+        assert_eq!(parse(&["--fullscreen"]), Ok(NetRole::Offline));
+    }
+
+    #[test]
+    fn host_uses_given_or_default_port() {
+        assert_eq!(parse(&["--host"]), Ok(NetRole::Host { port: DEFAULT_PORT }));
+        assert_eq!(parse(&["--host", "6000"]), Ok(NetRole::Host { port: 6000 }));
+        assert!(parse(&["--host", "sixty"]).is_err());
+        assert!(parse(&["--host", "70000"]).is_err());
+    }
+
+    #[test]
+    fn join_uses_given_or_default_port() {
+        assert_eq!(
+            parse(&["--join", "127.0.0.1"]),
+            Ok(NetRole::Client {
+                host: SocketAddr::from(([127, 0, 0, 1], DEFAULT_PORT))
+            })
+        );
+        assert_eq!(
+            parse(&["--verbose", "--join", "127.0.0.1:6000"]),
+            Ok(NetRole::Client {
+                host: SocketAddr::from(([127, 0, 0, 1], 6000))
+            })
+        );
+    }
+
+    #[test]
+    fn join_needs_an_address() {
+        assert!(parse(&["--join"]).is_err());
+        assert!(parse(&["--join", "127.0.0.1:port"]).is_err());
+    }
+}
@@ -0,0 +1,788 @@
+use super::{components::*, protocol::*, resources::*};
+use super::{CONNECTION_TIMEOUT, CORRECTION_RATE, CORRECTION_SNAP_DISTANCE, MAX_PENDING_INPUTS};
+use crate::{
+    animation::components::Animation,
+    arena::resources::Arena,
+    enemy::{components::Enemy, ENEMY_PULSE, ENEMY_SPEED},
+    game::states::AppState,
+    game_time::resources::GameTime,
+    level::resources::Level,
+    loading::resources::GameAssets,
+    obstacle::components::{block_circle, Obstacle},
+    player::{components::*, events::PlayerHit, MAX_PLAYERS, PLAYER_SIZE, PLAYER_SPAWN_SPACING},
+    powerup::{
+        components::{Magnet, PowerUp, PowerUpKind, Shield, SlowMotion, SpeedBoost, TimedPowerUp},
+        POWER_UP_SIZE, SPEED_BOOST_FACTOR,
+    },
+    projectile::{components::Projectile, PROJECTILE_COLOR, PROJECTILE_SIZE},
+    score::resources::{PlayerScores, Score},
+    settings::resources::Settings,
+    star::{components::*, STAR_TWINKLE},
+};
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use rand::prelude::*;
+use std::{f32::consts::FRAC_PI_4, net::UdpSocket};
+
+type Effects<'a> = (
+    Option<&'a Shield>,
+    Option<&'a SlowMotion>,
+    Option<&'a Magnet>,
+    Option<&'a SpeedBoost>,
+);
+
+fn effect_kinds((shield, slow_motion, magnet, speed_boost): Effects) -> Vec<PowerUpKind> {
+    [
+        (shield.is_some(), PowerUpKind::Shield),
+        (slow_motion.is_some(), PowerUpKind::SlowMotion),
+        (magnet.is_some(), PowerUpKind::Magnet),
+        (speed_boost.is_some(), PowerUpKind::SpeedBoost),
+    ]
+    .into_iter()
+    .filter_map(|(active, kind)| active.then_some(kind))
+    .collect()
+}
+
+/// Holds a client's input to what local controls can produce, or drops it if it can't be
+/// made sense of.
+fn sanitise(input: PlayerInput) -> Option<PlayerInput> {
+    if !input.movement.is_finite() || input.aim.is_some_and(|aim| !aim.is_finite()) {
+        return None;
+    }
+    Some(PlayerInput {
+        movement: input.movement.clamp_length_max(1.0),
+        aim: input.aim.and_then(Vec2::try_normalize),
+        ..input
+    })
+}
+
+pub fn open_socket(mut commands: Commands, role: Res<NetRole>) {
+    match *role {
+        NetRole::Offline => {}
+        NetRole::Host { port } => {
+            match UdpSocket::bind(("0.0.0.0", port))
+                .and_then(|socket| socket.set_nonblocking(true).map(|_| socket))
+            {
+                Ok(socket) => {
+                    info!("Hosting on port {}", port);
+                    commands.insert_resource(NetHost::new(socket, port));
+                }
+                Err(err) => error!("Unable to host on port {}: {}", port, err),
+            }
+        }
+        NetRole::Client { host } => {
+            match UdpSocket::bind(("0.0.0.0", 0))
+                .and_then(|socket| socket.set_nonblocking(true).map(|_| socket))
+            {
+                Ok(socket) => commands.insert_resource(NetClient::new(socket, host)),
+                Err(err) => error!("Unable to open a socket to join {}: {}", host, err),
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn receive_client_messages(
+    mut commands: Commands,
+    mut host: ResMut<NetHost>,
+    mut input_query: Query<&mut PlayerInput>,
+    player_query: Query<&Player>,
+    arena: Res<Arena>,
+    level: Res<Level>,
+    game_assets: Res<GameAssets>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+) {
+    let mut received = HashMap::new();
+
+    for (address, message) in receive::<ClientMessage>(&host.socket) {
+        match message {
+            ClientMessage::Hello { version } if version != PROTOCOL_VERSION => {
+                host.send(
+                    address,
+                    &ServerMessage::Rejected(format!(
+                        "Host speaks protocol {}, not {}",
+                        PROTOCOL_VERSION, version
+                    )),
+                );
+            }
+            ClientMessage::Hello { .. } => {
+                // A repeated hello means our welcome got lost, so send it again.
+                let player = match host.clients.get_mut(&address) {
+                    Some(client) => {
+                        client.last_heard = time.elapsed_seconds();
+                        client.player
+                    }
+                    None => {
+                        let taken = player_query
+                            .iter()
+                            .map(|player| player.index)
+                            .chain(0..settings.controls.players())
+                            .chain(host.clients.values().map(|client| client.player))
+                            .collect::<HashSet<_>>();
+                        let Some(player) = (0..MAX_PLAYERS).find(|index| !taken.contains(index))
+                        else {
+                            host.send(
+                                address,
+                                &ServerMessage::Rejected("Game is full".to_string()),
+                            );
+                            continue;
+                        };
+
+                        let start = level.player_position(&arena)
+                            + Vec2::new(player as f32, -1.0) * PLAYER_SPAWN_SPACING;
+                        let entity = commands
+                            .spawn((
+                                Player { index: player },
+                                RemotePlayer { address },
+                                PlayerInput::default(),
+                                Velocity::default(),
+                                Dash::default(),
+                                SpriteBundle {
+                                    transform: Transform::from_translation(
+                                        arena.clamp(start.extend(0.0), PLAYER_SIZE * 0.5),
+                                    ),
+                                    texture: game_assets.player_texture(player).clone(),
+                                    ..default()
+                                },
+                            ))
+                            .id();
+                        host.clients.insert(
+                            address,
+                            ClientConnection {
+                                player,
+                                entity,
+                                last_sequence: 0,
+                                last_heard: time.elapsed_seconds(),
+                            },
+                        );
+                        info!("{} joined as P{}", address, player + 1);
+                        player
+                    }
+                };
+
+                host.send(
+                    address,
+                    &ServerMessage::Welcome {
+                        player,
+                        level: level.clone(),
+                        world: settings.world.clone(),
+                        controls: settings.controls.clone(),
+                    },
+                );
+            }
+            ClientMessage::Input { sequence, input } => {
+                let Some(client) = host.clients.get_mut(&address) else {
+                    continue;
+                };
+                client.last_heard = time.elapsed_seconds();
+                if sequence <= client.last_sequence {
+                    continue;
+                }
+                let Some(input) = sanitise(input) else {
+                    warn!("Dropped invalid input from {}", address);
+                    continue;
+                };
+                client.last_sequence = sequence;
+
+                // Several inputs can land in one frame; keep the newest but don't lose a dash.
+                let dash = received
+                    .get(&address)
+                    .is_some_and(|input: &PlayerInput| input.dash);
+                received.insert(
+                    address,
+                    PlayerInput {
+                        dash: input.dash || dash,
+                        ..input
+                    },
+                );
+            }
+            ClientMessage::Bye => {
+                if let Some(client) = host.clients.remove(&address) {
+                    info!("P{} left", client.player + 1);
+                    if let Some(entity) = commands.get_entity(client.entity) {
+                        entity.despawn_recursive();
+                    }
+                }
+            }
+        }
+    }
+
+    for (address, client) in host.clients.iter() {
+        let Ok(mut input) = input_query.get_mut(client.entity) else {
+            continue;
+        };
+        match received.get(address) {
+            Some(next) => *input = *next,
+            // Without news the player keeps going the same way, but a dash is only asked once.
+            None if input.dash => input.dash = false,
+            None => {}
+        }
+    }
+}
+
+pub fn drop_silent_clients(mut commands: Commands, mut host: ResMut<NetHost>, time: Res<Time>) {
+    let now = time.elapsed_seconds();
+    host.clients.retain(|address, client| {
+        let alive = now - client.last_heard < CONNECTION_TIMEOUT;
+        if !alive {
+            info!("Lost {} (P{})", address, client.player + 1);
+            if let Some(entity) = commands.get_entity(client.entity) {
+                entity.despawn_recursive();
+            }
+        }
+        alive
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn broadcast_snapshot(
+    mut host: ResMut<NetHost>,
+    player_query: Query<(Entity, &Player, &Transform, &Velocity, Effects)>,
+    enemy_query: Query<(Entity, &Enemy, &Transform)>,
+    star_query: Query<(Entity, &Star, &Transform)>,
+    projectile_query: Query<(Entity, &Projectile, &Transform)>,
+    power_up_query: Query<(Entity, &PowerUp, &Transform)>,
+    score: Res<Score>,
+    player_scores: Res<PlayerScores>,
+    game_time: Res<GameTime>,
+    time: Res<Time>,
+) {
+    host.snapshot_timer.tick(time.delta());
+    if !host.snapshot_timer.just_finished() || host.clients.is_empty() {
+        return;
+    }
+    host.tick += 1;
+
+    let enemy_speed = ENEMY_SPEED * game_time.enemy_scale();
+    let mut snapshot = Snapshot {
+        tick: host.tick,
+        ack: 0,
+        players: player_query
+            .iter()
+            .map(
+                |(entity, player, transform, velocity, effects)| PlayerState {
+                    id: entity.to_bits(),
+                    index: player.index,
+                    position: transform.translation.truncate(),
+                    velocity: velocity.0,
+                    effects: effect_kinds(effects),
+                },
+            )
+            .collect(),
+        enemies: enemy_query
+            .iter()
+            .map(|(entity, enemy, transform)| EntityState {
+                id: entity.to_bits(),
+                position: transform.translation.truncate(),
+                velocity: enemy.direction * enemy_speed,
+            })
+            .collect(),
+        stars: star_query
+            .iter()
+            .map(|(entity, star, transform)| StarState {
+                id: entity.to_bits(),
+                position: transform.translation.truncate(),
+                tier: star.tier,
+            })
+            .collect(),
+        projectiles: projectile_query
+            .iter()
+            .map(|(entity, projectile, transform)| EntityState {
+                id: entity.to_bits(),
+                position: transform.translation.truncate(),
+                velocity: projectile.velocity,
+            })
+            .collect(),
+        power_ups: power_up_query
+            .iter()
+            .map(|(entity, power_up, transform)| PowerUpState {
+                id: entity.to_bits(),
+                position: transform.translation.truncate(),
+                kind: power_up.kind,
+            })
+            .collect(),
+        score: score.0,
+        player_scores: player_scores.0.clone(),
+        time_scale: game_time.effective_scale(),
+    };
+
+    for (address, client) in host.clients.iter() {
+        snapshot.ack = client.last_sequence;
+        host.send(*address, &ServerMessage::Snapshot(snapshot.clone()));
+    }
+}
+
+pub fn end_round(mut host: ResMut<NetHost>, score: Res<Score>) {
+    for address in host.clients.keys() {
+        host.send(*address, &ServerMessage::RoundOver { score: score.0 });
+    }
+    host.clients.clear();
+}
+
+pub fn toggle_joining(keyboard_input: Res<Input<KeyCode>>, mut client: ResMut<NetClient>) {
+    if keyboard_input.just_pressed(KeyCode::J) {
+        client.joining = !client.joining;
+    }
+}
+
+pub fn join_host(
+    mut client: ResMut<NetClient>,
+    mut level: ResMut<Level>,
+    mut arena: ResMut<Arena>,
+    mut next_state: ResMut<NextState<AppState>>,
+    time: Res<Time>,
+) {
+    for (address, message) in receive::<ServerMessage>(&client.socket) {
+        if address != client.host {
+            continue;
+        }
+        match message {
+            ServerMessage::Welcome {
+                player,
+                level: host_level,
+                world,
+                controls,
+            } => {
+                info!("Joined {} as P{}", client.host, player + 1);
+                *arena = host_level.arena(&world);
+                *level = host_level;
+                client.controls = Some(controls);
+
+                client.player = Some(player);
+                client.pending.clear();
+                client.snapshot = None;
+                client.last_tick = 0;
+                client.last_heard = time.elapsed_seconds();
+                next_state.set(AppState::Online);
+                return;
+            }
+            ServerMessage::Rejected(reason) => {
+                error!("{} turned us away: {}", client.host, reason);
+                client.joining = false;
+            }
+            _ => {}
+        }
+    }
+
+    if client.joining && client.hello_timer.tick(time.delta()).just_finished() {
+        client.send(&ClientMessage::Hello {
+            version: PROTOCOL_VERSION,
+        });
+    }
+}
+
+pub fn receive_server_messages(
+    mut client: ResMut<NetClient>,
+    mut score: ResMut<Score>,
+    mut next_state: ResMut<NextState<AppState>>,
+    time: Res<Time>,
+) {
+    for (address, message) in receive::<ServerMessage>(&client.socket) {
+        if address != client.host {
+            continue;
+        }
+        client.last_heard = time.elapsed_seconds();
+        match message {
+            // Snapshots can arrive out of order, and only the newest one matters.
+            ServerMessage::Snapshot(snapshot) if snapshot.tick > client.last_tick => {
+                client.last_tick = snapshot.tick;
+                client.snapshot = Some(snapshot);
+            }
+            ServerMessage::RoundOver { score: final_score } => {
+                info!("Round over! Score: {}", final_score);
+                score.0 = final_score;
+                next_state.set(AppState::MainMenu);
+                return;
+            }
+            _ => {}
+        }
+    }
+
+    if time.elapsed_seconds() - client.last_heard > CONNECTION_TIMEOUT {
+        warn!("Lost the connection to {}", client.host);
+        next_state.set(AppState::MainMenu);
+    }
+}
+
+pub fn apply_snapshot(
+    mut commands: Commands,
+    mut client: ResMut<NetClient>,
+    mut replicated_query: Query<(&mut Replicated, &mut Transform)>,
+    game_assets: Res<GameAssets>,
+    mut score: ResMut<Score>,
+    mut player_scores: ResMut<PlayerScores>,
+    mut game_time: ResMut<GameTime>,
+) {
+    let client = &mut *client;
+    let Some(snapshot) = client.snapshot.as_ref() else {
+        return;
+    };
+
+    if score.0 != snapshot.score {
+        score.0 = snapshot.score;
+    }
+    if player_scores.0 != snapshot.player_scores {
+        player_scores.0 = snapshot.player_scores.clone();
+    }
+    // Pausing, fast-forward and hit-stop on the host all reach us through its clock.
+    if game_time.scale != snapshot.time_scale {
+        game_time.scale = snapshot.time_scale;
+    }
+
+    // Other players are drawn like everything else the host simulates; ours is predicted.
+    let players = snapshot
+        .players
+        .iter()
+        .filter(|player| Some(player.index) != client.player)
+        .map(|player| {
+            let kind = ReplicatedKind::Player(player.index);
+            (player.id, player.position, player.velocity, kind)
+        });
+    let enemies = snapshot.enemies.iter().map(|enemy| {
+        let kind = ReplicatedKind::Enemy;
+        (enemy.id, enemy.position, enemy.velocity, kind)
+    });
+    let stars = snapshot.stars.iter().map(|star| {
+        let kind = ReplicatedKind::Star(star.tier);
+        (star.id, star.position, Vec2::ZERO, kind)
+    });
+    let projectiles = snapshot.projectiles.iter().map(|projectile| {
+        let kind = ReplicatedKind::Projectile;
+        (
+            projectile.id,
+            projectile.position,
+            projectile.velocity,
+            kind,
+        )
+    });
+    let power_ups = snapshot.power_ups.iter().map(|power_up| {
+        let kind = ReplicatedKind::PowerUp(power_up.kind);
+        (power_up.id, power_up.position, Vec2::ZERO, kind)
+    });
+
+    let mut seen = HashSet::new();
+    let replicated = players
+        .chain(enemies)
+        .chain(stars)
+        .chain(projectiles)
+        .chain(power_ups);
+    for (id, position, velocity, kind) in replicated {
+        seen.insert(id);
+        let translation = position.extend(kind.z());
+
+        let existing = client
+            .replicated
+            .get(&id)
+            .and_then(|entity| replicated_query.get_mut(*entity).ok());
+        if let Some((mut replicated, mut transform)) = existing {
+            replicated.velocity = velocity;
+            transform.translation = translation;
+            continue;
+        }
+
+        let transform = Transform::from_translation(translation);
+        let mut entity = match kind {
+            ReplicatedKind::Player(index) => commands.spawn(SpriteBundle {
+                transform,
+                texture: game_assets.player_texture(index).clone(),
+                ..default()
+            }),
+            ReplicatedKind::Enemy => commands.spawn((
+                Animation::new(ENEMY_PULSE).starting_at(random()),
+                SpriteSheetBundle {
+                    transform,
+                    texture_atlas: game_assets.enemy_atlas.clone(),
+                    ..default()
+                },
+            )),
+            ReplicatedKind::Star(tier) => commands.spawn((
+                Animation::new(STAR_TWINKLE).starting_at(random()),
+                SpriteSheetBundle {
+                    transform,
+                    texture_atlas: game_assets.star_atlas(tier).clone(),
+                    ..default()
+                },
+            )),
+            ReplicatedKind::Projectile => commands.spawn(SpriteBundle {
+                transform,
+                sprite: Sprite {
+                    color: PROJECTILE_COLOR,
+                    custom_size: Some(Vec2::splat(PROJECTILE_SIZE)),
+                    ..default()
+                },
+                ..default()
+            }),
+            ReplicatedKind::PowerUp(kind) => commands.spawn(SpriteBundle {
+                transform: transform.with_rotation(Quat::from_rotation_z(FRAC_PI_4)),
+                sprite: Sprite {
+                    color: kind.color(),
+                    custom_size: Some(Vec2::splat(POWER_UP_SIZE)),
+                    ..default()
+                },
+                ..default()
+            }),
+        };
+        entity.insert(Replicated { id, velocity });
+        client.replicated.insert(id, entity.id());
+    }
+
+    client.replicated.retain(|id, entity| {
+        let keep = seen.contains(id);
+        if !keep {
+            commands.entity(*entity).despawn_recursive();
+        }
+        keep
+    });
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn reconcile_player(
+    mut commands: Commands,
+    mut client: ResMut<NetClient>,
+    mut player_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut Velocity,
+            &Dash,
+            Option<&SpeedBoost>,
+        ),
+        With<Player>,
+    >,
+    obstacle_query: Query<(&Obstacle, &Transform), Without<Player>>,
+    mut player_hit_event_writer: EventWriter<PlayerHit>,
+    game_assets: Res<GameAssets>,
+    arena: Res<Arena>,
+    game_time: Res<GameTime>,
+) {
+    let (Some(index), Some(snapshot), Some(controls)) = (
+        client.player,
+        client.snapshot.take(),
+        client.controls.clone(),
+    ) else {
+        return;
+    };
+    let state = snapshot.players.iter().find(|player| player.index == index);
+    let local = player_query.get_single_mut().ok();
+
+    let (state, (entity, mut transform, mut velocity, dash, speed_boost)) = match (state, local) {
+        (Some(state), Some(local)) => (state, local),
+        (Some(state), None) => {
+            commands.spawn((
+                Player { index },
+                Controls::for_player(0, 1),
+                PlayerInput::default(),
+                Velocity(state.velocity),
+                Dash::default(),
+                SpriteBundle {
+                    transform: Transform::from_translation(state.position.extend(0.0)),
+                    texture: game_assets.player_texture(index).clone(),
+                    ..default()
+                },
+            ));
+            return;
+        }
+        (None, Some((entity, transform, _, _, _))) => {
+            // Gone from the host's world, so the host saw us die.
+            commands.entity(entity).despawn_recursive();
+            player_hit_event_writer.send(PlayerHit {
+                player: index,
+                position: transform.translation,
+            });
+            return;
+        }
+        (None, None) => return,
+    };
+
+    client
+        .pending
+        .retain(|pending| pending.sequence > snapshot.ack);
+
+    // Carry the host's speed boost so that prediction and replay move as fast as it does.
+    let boosted = state.effects.contains(&PowerUpKind::SpeedBoost);
+    if boosted != speed_boost.is_some() {
+        if boosted {
+            commands.entity(entity).insert(SpeedBoost::new());
+        } else {
+            commands.entity(entity).remove::<SpeedBoost>();
+        }
+    }
+    let speed_factor = if boosted { SPEED_BOOST_FACTOR } else { 1.0 };
+
+    if dash.is_dashing() {
+        return;
+    }
+
+    // Start from where the host had us and replay what it hasn't seen yet, going through
+    // the same confinement and obstacles as `confine_player_movement` and `block_player`.
+    let mut position = state.position;
+    let mut replayed = state.velocity;
+    for pending in client.pending.iter() {
+        replayed = controls.steer(
+            replayed,
+            pending.input.movement,
+            speed_factor,
+            pending.delta,
+        );
+        let moved = position + replayed * pending.delta;
+        let confined = arena
+            .confine(moved.extend(0.0), PLAYER_SIZE * 0.5)
+            .truncate();
+        if !arena.wraps() {
+            if confined.x != moved.x {
+                replayed.x = 0.0;
+            }
+            if confined.y != moved.y {
+                replayed.y = 0.0;
+            }
+        }
+        (position, replayed) =
+            block_circle(obstacle_query.iter(), confined, replayed, PLAYER_SIZE * 0.5);
+    }
+    let position = position.extend(transform.translation.z);
+
+    let error = arena.delta(transform.translation, position);
+    if error.length() > CORRECTION_SNAP_DISTANCE {
+        transform.translation = position;
+    } else {
+        let blend = (CORRECTION_RATE * game_time.delta_seconds()).min(1.0);
+        transform.translation = arena.confine(
+            transform.translation + (error * blend).extend(0.0),
+            PLAYER_SIZE * 0.5,
+        );
+    }
+    velocity.0 = replayed;
+}
+
+pub fn send_input(
+    mut client: ResMut<NetClient>,
+    query: Query<&PlayerInput, With<Player>>,
+    game_time: Res<GameTime>,
+) {
+    let Ok(input) = query.get_single() else {
+        return;
+    };
+
+    client.sequence += 1;
+    let sequence = client.sequence;
+    client.send(&ClientMessage::Input {
+        sequence,
+        input: *input,
+    });
+
+    client.pending.push_back(PendingInput {
+        sequence,
+        input: *input,
+        delta: game_time.delta_seconds(),
+    });
+    while client.pending.len() > MAX_PENDING_INPUTS {
+        client.pending.pop_front();
+    }
+}
+
+pub fn extrapolate_replicated(
+    mut query: Query<(&Replicated, &mut Transform)>,
+    arena: Res<Arena>,
+    game_time: Res<GameTime>,
+) {
+    for (replicated, mut transform) in query.iter_mut() {
+        if replicated.velocity == Vec2::ZERO {
+            continue;
+        }
+        transform.translation = arena.confine(
+            transform.translation + replicated.velocity.extend(0.0) * game_time.delta_seconds(),
+            0.0,
+        );
+    }
+}
+
+pub fn leave_host(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut client: ResMut<NetClient>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Back) {
+        client.send(&ClientMessage::Bye);
+        client.joining = false;
+        next_state.set(AppState::MainMenu);
+    }
+}
+
+pub fn despawn_replicated(
+    mut commands: Commands,
+    mut client: ResMut<NetClient>,
+    query: Query<Entity, With<Replicated>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    client.replicated.clear();
+    client.player = None;
+    client.controls = None;
+}
+
+pub fn spawn_net_status_text(
+    mut commands: Commands,
+    role: Res<NetRole>,
+    game_assets: Res<GameAssets>,
+) {
+    if *role == NetRole::Offline {
+        return;
+    }
+
+    commands.spawn((
+        NetStatusText {},
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: game_assets.font.clone(),
+                font_size: 20.0,
+                color: Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                left: Val::Px(10.0),
+                top: Val::Px(10.0),
+                ..default()
+            },
+            ..default()
+        }),
+    ));
+}
+
+pub fn update_net_status_text(
+    mut text_query: Query<&mut Text, With<NetStatusText>>,
+    host: Option<Res<NetHost>>,
+    client: Option<Res<NetClient>>,
+    state: Res<State<AppState>>,
+) {
+    let value = match (host, client) {
+        (Some(host), _) => format!(
+            "Hosting on port {} - {} connected",
+            host.port,
+            host.clients.len()
+        ),
+        (_, Some(client)) => match (state.0, client.player) {
+            (AppState::Online, Some(player)) => {
+                format!("Online as P{} - Backspace to leave", player + 1)
+            }
+            (AppState::MainMenu, _) if client.joining => {
+                format!("Joining {} - J to stop", client.host)
+            }
+            (AppState::MainMenu, _) => format!("J to join {}", client.host),
+            _ => String::new(),
+        },
+        _ => return,
+    };
+
+    for mut text in text_query.iter_mut() {
+        if text.sections[0].value != value {
+            text.sections[0].value = value.clone();
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Outline of an obstacle, relative to its position. Polygons are expected to be convex.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Shape {
+    Rectangle { width: f32, height: f32 },
+    Circle { radius: f32 },
+    Polygon { points: Vec<Vec2> },
+}
+
+impl Shape {
+    /// Rejects shapes the collision maths can't handle: empty or non-finite sizes, and
+    /// polygons with fewer than three points or with repeated consecutive points.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Shape::Rectangle { width, height } => {
+                if !(width.is_finite() && height.is_finite() && *width > 0.0 && *height > 0.0) {
+                    return Err(format!("Rectangle of {} by {} has no area", width, height));
+                }
+            }
+            Shape::Circle { radius } => {
+                if !(radius.is_finite() && *radius > 0.0) {
+                    return Err(format!("Circle radius {} must be positive", radius));
+                }
+            }
+            Shape::Polygon { points } => {
+                if points.len() < 3 {
+                    return Err(format!(
+                        "Polygon has {} points, needs at least 3",
+                        points.len()
+                    ));
+                }
+                if !points.iter().all(|point| point.is_finite()) {
+                    return Err("Polygon has a non-finite point".to_string());
+                }
+                if let Some((a, _)) = edges(points).find(|(a, b)| a.distance_squared(*b) == 0.0) {
+                    return Err(format!("Polygon repeats the point {}", a));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        match self {
+            Shape::Rectangle { width, height } => {
+                point.x.abs() <= width * 0.5 && point.y.abs() <= height * 0.5
+            }
+            Shape::Circle { radius } => point.length() <= *radius,
+            Shape::Polygon { points } => {
+                let mut inside = false;
+                for (a, b) in edges(points) {
+                    if (a.y > point.y) != (b.y > point.y)
+                        && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+                    {
+                        inside = !inside;
+                    }
+                }
+                inside
+            }
+        }
+    }
+
+    /// Size of the bounding box of the shape.
+    pub fn size(&self) -> Vec2 {
+        match self {
+            Shape::Rectangle { width, height } => Vec2::new(*width, *height),
+            Shape::Circle { radius } => Vec2::splat(radius * 2.0),
+            Shape::Polygon { points } => {
+                let min = points
+                    .iter()
+                    .copied()
+                    .fold(Vec2::splat(f32::MAX), Vec2::min);
+                let max = points
+                    .iter()
+                    .copied()
+                    .fold(Vec2::splat(f32::MIN), Vec2::max);
+                (max - min).max(Vec2::ZERO)
+            }
+        }
+    }
+
+    pub fn scaled(&self, factor: f32) -> Self {
+        match self {
+            Shape::Rectangle { width, height } => Shape::Rectangle {
+                width: width * factor,
+                height: height * factor,
+            },
+            Shape::Circle { radius } => Shape::Circle {
+                radius: radius * factor,
+            },
+            Shape::Polygon { points } => Shape::Polygon {
+                points: points.iter().map(|point| *point * factor).collect(),
+            },
+        }
+    }
+
+    /// Closest point on the outline of the shape.
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        match self {
+            Shape::Rectangle { width, height } => {
+                let half = Vec2::new(width * 0.5, height * 0.5);
+                let clamped = point.clamp(-half, half);
+                if clamped != point {
+                    return clamped;
+                }
+                let gap = half - point.abs();
+                if gap.x < gap.y {
+                    Vec2::new(half.x.copysign(point.x), point.y)
+                } else {
+                    Vec2::new(point.x, half.y.copysign(point.y))
+                }
+            }
+            Shape::Circle { radius } => point.try_normalize().unwrap_or(Vec2::X) * *radius,
+            Shape::Polygon { points } => edges(points)
+                .map(|(a, b)| {
+                    let edge = b - a;
+                    let length_squared = edge.length_squared();
+                    if length_squared == 0.0 {
+                        return a;
+                    }
+                    let t = ((point - a).dot(edge) / length_squared).clamp(0.0, 1.0);
+                    a + edge * t
+                })
+                .min_by(|p, q| {
+                    p.distance_squared(point)
+                        .total_cmp(&q.distance_squared(point))
+                })
+                .unwrap_or(Vec2::ZERO),
+        }
+    }
+
+    /// Pushes a circle at `point` out of the shape, returning its new position and the
+    /// surface normal if the two overlap.
+    pub fn resolve(&self, point: Vec2, radius: f32) -> Option<(Vec2, Vec2)> {
+        let inside = self.contains(point);
+        let closest = self.closest_point(point);
+        let offset = point - closest;
+
+        if !inside && offset.length() >= radius {
+            return None;
+        }
+
+        let normal = if inside { -offset } else { offset }
+            .try_normalize()
+            .unwrap_or_else(|| closest.try_normalize().unwrap_or(Vec2::Y));
+        Some((closest + normal * radius, normal))
+    }
+}
+
+fn edges(points: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+#[derive(Component)]
+pub struct Obstacle {
+    pub shape: Shape,
+}
+
+/// Pushes a circle out of each obstacle in turn, dropping any velocity that would carry it
+/// back in. Returns the corrected position and velocity.
+pub fn block_circle<'a>(
+    obstacles: impl IntoIterator<Item = (&'a Obstacle, &'a Transform)>,
+    mut position: Vec2,
+    mut velocity: Vec2,
+    radius: f32,
+) -> (Vec2, Vec2) {
+    for (obstacle, transform) in obstacles {
+        let origin = transform.translation.truncate();
+        let Some((local, normal)) = obstacle.shape.resolve(position - origin, radius) else {
+            continue;
+        };
+
+        position = origin + local;
+        velocity -= velocity.dot(normal).min(0.0) * normal;
+    }
+    (position, velocity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Shape {
+        Shape::Polygon {
+            points: vec![
+                Vec2::new(-10.0, -10.0),
+                Vec2::new(10.0, -10.0),
+                Vec2::new(10.0, 10.0),
+                Vec2::new(-10.0, 10.0),
+            ],
+        }
+    }
+
+    #[test]
+    fn rectangle_contains() {
+        let shape = Shape::Rectangle {
+            width: 20.0,
+            height: 10.0,
+        };
+        assert!(shape.contains(Vec2::ZERO));
+        assert!(shape.contains(Vec2::new(10.0, 5.0)));
+        assert!(!shape.contains(Vec2::new(10.5, 0.0)));
+        assert!(!shape.contains(Vec2::new(0.0, -5.5)));
+    }
+
+    #[test]
+    fn rectangle_closest_point() {
+        let shape = Shape::Rectangle {
+            width: 20.0,
+            height: 10.0,
+        };
+        assert_eq!(
+            shape.closest_point(Vec2::new(20.0, 2.0)),
+            Vec2::new(10.0, 2.0)
+        );
+        assert_eq!(
+            shape.closest_point(Vec2::new(20.0, 20.0)),
+            Vec2::new(10.0, 5.0)
+        );
+        // From inside, the nearest edge wins.
+        assert_eq!(
+            shape.closest_point(Vec2::new(1.0, 4.0)),
+            Vec2::new(1.0, 5.0)
+        );
+        assert_eq!(
+            shape.closest_point(Vec2::new(-9.0, 0.0)),
+            Vec2::new(-10.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn rectangle_resolve() {
+        let shape = Shape::Rectangle {
+            width: 20.0,
+            height: 10.0,
+        };
+        assert_eq!(shape.resolve(Vec2::new(20.0, 0.0), 5.0), None);
+        assert_eq!(
+            shape.resolve(Vec2::new(12.0, 0.0), 5.0),
+            Some((Vec2::new(15.0, 0.0), Vec2::X))
+        );
+        assert_eq!(
+            shape.resolve(Vec2::new(0.0, 4.0), 5.0),
+            Some((Vec2::new(0.0, 10.0), Vec2::Y))
+        );
+    }
+
+    #[test]
+    fn circle_contains() {
+        let shape = Shape::Circle { radius: 10.0 };
+        assert!(shape.contains(Vec2::ZERO));
+        assert!(shape.contains(Vec2::new(6.0, 8.0)));
+        assert!(!shape.contains(Vec2::new(8.0, 8.0)));
+    }
+
+    #[test]
+    fn circle_closest_point() {
+        let shape = Shape::Circle { radius: 10.0 };
+        assert_eq!(
+            shape.closest_point(Vec2::new(30.0, 0.0)),
+            Vec2::new(10.0, 0.0)
+        );
+        assert_eq!(
+            shape.closest_point(Vec2::new(0.0, -2.0)),
+            Vec2::new(0.0, -10.0)
+        );
+        assert_eq!(shape.closest_point(Vec2::ZERO), Vec2::new(10.0, 0.0));
+    }
+
+    #[test]
+    fn circle_resolve() {
+        let shape = Shape::Circle { radius: 10.0 };
+        assert_eq!(shape.resolve(Vec2::new(0.0, 20.0), 5.0), None);
+        assert_eq!(
+            shape.resolve(Vec2::new(0.0, 12.0), 5.0),
+            Some((Vec2::new(0.0, 15.0), Vec2::Y))
+        );
+        assert_eq!(
+            shape.resolve(Vec2::new(-4.0, 0.0), 5.0),
+            Some((Vec2::new(-15.0, 0.0), Vec2::NEG_X))
+        );
+    }
+
+    #[test]
+    fn polygon_contains() {
+        let shape = square();
+        assert!(shape.contains(Vec2::ZERO));
+        assert!(shape.contains(Vec2::new(9.0, -9.0)));
+        assert!(!shape.contains(Vec2::new(11.0, 0.0)));
+        assert!(!shape.contains(Vec2::new(0.0, 11.0)));
+    }
+
+    #[test]
+    fn polygon_closest_point() {
+        let shape = square();
+        assert_eq!(
+            shape.closest_point(Vec2::new(20.0, 3.0)),
+            Vec2::new(10.0, 3.0)
+        );
+        assert_eq!(
+            shape.closest_point(Vec2::new(-20.0, -20.0)),
+            Vec2::new(-10.0, -10.0)
+        );
+        assert_eq!(
+            shape.closest_point(Vec2::new(2.0, 8.0)),
+            Vec2::new(2.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn polygon_resolve() {
+        let shape = square();
+        assert_eq!(shape.resolve(Vec2::new(20.0, 0.0), 5.0), None);
+        assert_eq!(
+            shape.resolve(Vec2::new(13.0, 0.0), 5.0),
+            Some((Vec2::new(15.0, 0.0), Vec2::X))
+        );
+        assert_eq!(
+            shape.resolve(Vec2::new(0.0, -8.0), 5.0),
+            Some((Vec2::new(0.0, -15.0), Vec2::NEG_Y))
+        );
+    }
+
+    #[test]
+    fn polygon_with_repeated_point_has_finite_closest_point() {
+        let shape = Shape::Polygon {
+            points: vec![
+                Vec2::new(-10.0, -10.0),
+                Vec2::new(-10.0, -10.0),
+                Vec2::new(10.0, -10.0),
+                Vec2::new(0.0, 10.0),
+            ],
+        };
+        assert!(shape.closest_point(Vec2::new(0.0, -20.0)).is_finite());
+        assert!(shape.validate().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_degenerate_shapes() {
+        assert!(square().validate().is_ok());
+        assert!(Shape::Circle { radius: 0.0 }.validate().is_err());
+        assert!(Shape::Circle { radius: f32::NAN }.validate().is_err());
+        assert!(Shape::Rectangle {
+            width: 10.0,
+            height: -1.0
+        }
+        .validate()
+        .is_err());
+        assert!(Shape::Polygon {
+            points: vec![Vec2::ZERO, Vec2::X]
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod systems;
+
+use crate::{
+    enemy::EnemyMovementSet,
+    game::states::AppState,
+    level::resources::Level,
+    player::{PlayerCollisionSet, PlayerMovementSet},
+};
+use systems::*;
+
+pub const OBSTACLE_COLOR: Color = Color::rgb(0.2, 0.2, 0.25);
+pub const CIRCLE_VERTICES: usize = 48;
+
+pub struct ObstaclePlugin;
+
+impl Plugin for ObstaclePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(spawn_obstacles.in_schedule(OnEnter(AppState::Game)))
+            .add_system(despawn_obstacles.in_schedule(OnExit(AppState::Game)))
+            .add_system(spawn_obstacles.in_schedule(OnEnter(AppState::Editor)))
+            .add_system(despawn_obstacles.in_schedule(OnExit(AppState::Editor)))
+            .add_systems(
+                (despawn_obstacles, spawn_obstacles)
+                    .chain()
+                    .distributive_run_if(resource_changed::<Level>())
+                    .in_set(OnUpdate(AppState::Editor)),
+            )
+            .add_system(
+                block_player
+                    .after(PlayerMovementSet)
+                    .before(PlayerCollisionSet)
+                    .in_set(OnUpdate(AppState::Game)),
+            )
+            .add_system(
+                bounce_enemies_off_obstacles
+                    .after(EnemyMovementSet)
+                    .in_set(OnUpdate(AppState::Game)),
+            )
+            .add_system(spawn_obstacles.in_schedule(OnEnter(AppState::Online)))
+            .add_system(despawn_obstacles.in_schedule(OnExit(AppState::Online)))
+            .add_system(
+                block_player
+                    .after(PlayerMovementSet)
+                    .in_set(OnUpdate(AppState::Online)),
+            );
+    }
+}
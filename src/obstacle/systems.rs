@@ -0,0 +1,145 @@
+use super::{components::*, CIRCLE_VERTICES, OBSTACLE_COLOR};
+use crate::{
+    enemy::{components::Enemy, ENEMY_SIZE},
+    level::resources::Level,
+    player::{
+        components::{Player, Velocity},
+        PLAYER_SIZE,
+    },
+    sound::{events::PlaySoundEffect, resources::SoundEffect},
+};
+use bevy::{
+    prelude::*,
+    render::{mesh::Indices, render_resource::PrimitiveTopology},
+    sprite::MaterialMesh2dBundle,
+};
+
+fn polygon_mesh(points: &[Vec2]) -> Mesh {
+    let positions: Vec<[f32; 3]> = points.iter().map(|p| [p.x, p.y, 0.0]).collect();
+    let normals = vec![[0.0, 0.0, 1.0]; points.len()];
+    let uvs = vec![[0.0, 0.0]; points.len()];
+    let indices = (1..points.len().saturating_sub(1) as u32)
+        .flat_map(|i| [0, i, i + 1])
+        .collect();
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh
+}
+
+pub fn spawn_obstacles(
+    mut commands: Commands,
+    level: Res<Level>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for definition in level.obstacles.iter() {
+        let transform = Transform::from_translation(definition.position.extend(-0.5));
+        let obstacle = Obstacle {
+            shape: definition.shape.clone(),
+        };
+
+        match &definition.shape {
+            Shape::Rectangle { width, height } => {
+                commands.spawn((
+                    obstacle,
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: OBSTACLE_COLOR,
+                            custom_size: Some(Vec2::new(*width, *height)),
+                            ..default()
+                        },
+                        transform,
+                        ..default()
+                    },
+                ));
+            }
+            Shape::Circle { radius } => {
+                commands.spawn((
+                    obstacle,
+                    MaterialMesh2dBundle {
+                        mesh: meshes
+                            .add(
+                                shape::Circle {
+                                    radius: *radius,
+                                    vertices: CIRCLE_VERTICES,
+                                }
+                                .into(),
+                            )
+                            .into(),
+                        material: materials.add(ColorMaterial::from(OBSTACLE_COLOR)),
+                        transform,
+                        ..default()
+                    },
+                ));
+            }
+            Shape::Polygon { points } => {
+                commands.spawn((
+                    obstacle,
+                    MaterialMesh2dBundle {
+                        mesh: meshes.add(polygon_mesh(points)).into(),
+                        material: materials.add(ColorMaterial::from(OBSTACLE_COLOR)),
+                        transform,
+                        ..default()
+                    },
+                ));
+            }
+        }
+    }
+}
+
+pub fn despawn_obstacles(mut commands: Commands, query: Query<Entity, With<Obstacle>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn block_player(
+    mut player_query: Query<(&mut Transform, &mut Velocity), (With<Player>, Without<Obstacle>)>,
+    obstacle_query: Query<(&Obstacle, &Transform)>,
+) {
+    for (mut player_transform, mut velocity) in player_query.iter_mut() {
+        let (position, blocked) = block_circle(
+            obstacle_query.iter(),
+            player_transform.translation.truncate(),
+            velocity.0,
+            PLAYER_SIZE * 0.5,
+        );
+        if position != player_transform.translation.truncate() {
+            player_transform.translation = position.extend(player_transform.translation.z);
+        }
+        if blocked != velocity.0 {
+            velocity.0 = blocked;
+        }
+    }
+}
+
+pub fn bounce_enemies_off_obstacles(
+    mut enemy_query: Query<(&mut Enemy, &mut Transform), Without<Obstacle>>,
+    obstacle_query: Query<(&Obstacle, &Transform)>,
+    mut play_sound_event_writer: EventWriter<PlaySoundEffect>,
+) {
+    for (mut enemy, mut enemy_transform) in enemy_query.iter_mut() {
+        for (obstacle, obstacle_transform) in obstacle_query.iter() {
+            let origin = obstacle_transform.translation.truncate();
+            let local = enemy_transform.translation.truncate() - origin;
+            let Some((position, normal)) = obstacle.shape.resolve(local, ENEMY_SIZE * 0.5) else {
+                continue;
+            };
+
+            enemy_transform.translation = (origin + position).extend(enemy_transform.translation.z);
+            let direction = enemy.direction;
+            if direction.dot(normal) < 0.0 {
+                enemy.direction = direction - 2.0 * direction.dot(normal) * normal;
+                play_sound_event_writer.send(PlaySoundEffect {
+                    effect: SoundEffect::Bounce,
+                    position: Some(enemy_transform.translation),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use super::{DASH_TRAIL_COLOR, DEBRIS_COLOR, EXPLOSION_COLOR};
+use crate::player::DASH_DURATION;
+use crate::star::components::StarTier;
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParticleEffect {
+    Pickup(StarTier),
+    Explosion,
+    Debris,
+    DashTrail,
+}
+
+impl ParticleEffect {
+    pub fn emitter(&self) -> ParticleEmitter {
+        match self {
+            ParticleEffect::Pickup(tier) => ParticleEmitter {
+                burst: 8 + 4 * tier.points().min(4) as usize,
+                rate: 0.0,
+                duration: Timer::from_seconds(0.0, TimerMode::Once),
+                particle: ParticleSettings {
+                    lifetime: 0.4,
+                    speed: (60.0, 180.0),
+                    damping: 3.0,
+                    size: 6.0,
+                    colors: (tier.color(), tier.color().with_a(0.0)),
+                    scales: (1.0, 0.3),
+                },
+                accumulator: 0.0,
+            },
+            ParticleEffect::Explosion => ParticleEmitter {
+                burst: 48,
+                rate: 120.0,
+                duration: Timer::from_seconds(0.25, TimerMode::Once),
+                particle: ParticleSettings {
+                    lifetime: 0.9,
+                    speed: (80.0, 420.0),
+                    damping: 2.0,
+                    size: 10.0,
+                    colors: (EXPLOSION_COLOR, Color::rgba(0.3, 0.3, 0.3, 0.0)),
+                    scales: (1.5, 0.5),
+                },
+                accumulator: 0.0,
+            },
+            ParticleEffect::Debris => ParticleEmitter {
+                burst: 24,
+                rate: 0.0,
+                duration: Timer::from_seconds(0.0, TimerMode::Once),
+                particle: ParticleSettings {
+                    lifetime: 0.5,
+                    speed: (60.0, 260.0),
+                    damping: 3.0,
+                    size: 7.0,
+                    colors: (DEBRIS_COLOR, DEBRIS_COLOR.with_a(0.0)),
+                    scales: (1.0, 0.4),
+                },
+                accumulator: 0.0,
+            },
+            ParticleEffect::DashTrail => ParticleEmitter {
+                burst: 4,
+                rate: 160.0,
+                duration: Timer::from_seconds(DASH_DURATION, TimerMode::Once),
+                particle: ParticleSettings {
+                    lifetime: 0.35,
+                    speed: (0.0, 40.0),
+                    damping: 4.0,
+                    size: 14.0,
+                    colors: (DASH_TRAIL_COLOR, DASH_TRAIL_COLOR.with_a(0.0)),
+                    scales: (1.0, 0.2),
+                },
+                accumulator: 0.0,
+            },
+        }
+    }
+}
+
+/// How each particle released by an emitter moves and changes over its lifetime.
+#[derive(Clone, Debug)]
+pub struct ParticleSettings {
+    pub lifetime: f32,
+    pub speed: (f32, f32),
+    pub damping: f32,
+    pub size: f32,
+    pub colors: (Color, Color),
+    pub scales: (f32, f32),
+}
+
+/// Releases `burst` particles when spawned, then `rate` per second until `duration`
+/// runs out, after which it despawns.
+#[derive(Component, Clone, Debug)]
+pub struct ParticleEmitter {
+    pub burst: usize,
+    pub rate: f32,
+    pub duration: Timer,
+    pub particle: ParticleSettings,
+    pub accumulator: f32,
+}
+
+#[derive(Component)]
+pub struct Particle {
+    pub velocity: Vec2,
+    pub age: Timer,
+    pub settings: ParticleSettings,
+}
+
+#[derive(Component)]
+pub struct Popup {
+    pub velocity: Vec2,
+    pub age: Timer,
+    pub color: Color,
+}
@@ -0,0 +1,7 @@
+use super::components::ParticleEffect;
+use bevy::prelude::*;
+
+pub struct SpawnParticles {
+    pub effect: ParticleEffect,
+    pub position: Vec3,
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+mod systems;
+
+use events::*;
+use systems::*;
+
+pub const PARTICLE_Z: f32 = 2.0;
+pub const POPUP_Z: f32 = 3.0;
+pub const POPUP_SPEED: f32 = 60.0;
+pub const POPUP_LIFETIME: f32 = 0.8;
+pub const POPUP_FONT_SIZE: f32 = 24.0;
+pub const POPUP_COLOR: Color = Color::WHITE;
+pub const NEAR_MISS_POPUP_COLOR: Color = Color::rgb(1.0, 0.4, 0.4);
+pub const EXPLOSION_COLOR: Color = Color::rgb(1.0, 0.6, 0.2);
+pub const DEBRIS_COLOR: Color = Color::rgb(1.0, 0.35, 0.35);
+pub const DASH_TRAIL_COLOR: Color = Color::rgb(0.4, 0.7, 1.0);
+
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SpawnParticles>().add_systems(
+            (
+                spawn_requested_particles,
+                spawn_pickup_effects,
+                spawn_near_miss_popups,
+                spawn_enemy_destroyed_effects,
+                update_emitters,
+                update_particles,
+                update_popups,
+            )
+                .chain(),
+        );
+    }
+}
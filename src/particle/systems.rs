@@ -0,0 +1,204 @@
+use super::{components::*, events::*};
+use super::{
+    NEAR_MISS_POPUP_COLOR, PARTICLE_Z, POPUP_COLOR, POPUP_FONT_SIZE, POPUP_LIFETIME, POPUP_SPEED,
+    POPUP_Z,
+};
+use crate::{
+    combo::events::NearMiss, enemy::events::EnemyDestroyed, game_time::resources::GameTime,
+    loading::resources::GameAssets, star::events::StarCollected,
+};
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::f32::consts::TAU;
+
+fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let from = Vec4::from(from.as_rgba_f32());
+    let to = Vec4::from(to.as_rgba_f32());
+    let [r, g, b, a] = from.lerp(to, t).to_array();
+    Color::rgba(r, g, b, a)
+}
+
+fn spawn_emitter(commands: &mut Commands, effect: ParticleEffect, position: Vec3) {
+    commands.spawn((
+        effect.emitter(),
+        TransformBundle::from_transform(Transform::from_translation(position)),
+    ));
+}
+
+fn spawn_popup(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    text: String,
+    color: Color,
+    position: Vec3,
+) {
+    commands.spawn((
+        Popup {
+            velocity: Vec2::Y * POPUP_SPEED,
+            age: Timer::from_seconds(POPUP_LIFETIME, TimerMode::Once),
+            color,
+        },
+        Text2dBundle {
+            text: Text::from_section(
+                text,
+                TextStyle {
+                    font: game_assets.font.clone(),
+                    font_size: POPUP_FONT_SIZE,
+                    color,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_translation(position.truncate().extend(POPUP_Z)),
+            ..default()
+        },
+    ));
+}
+
+pub fn spawn_requested_particles(
+    mut commands: Commands,
+    mut spawn_particles_event_reader: EventReader<SpawnParticles>,
+) {
+    for event in spawn_particles_event_reader.iter() {
+        spawn_emitter(&mut commands, event.effect, event.position);
+    }
+}
+
+pub fn spawn_pickup_effects(
+    mut commands: Commands,
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    game_assets: Res<GameAssets>,
+) {
+    for event in star_collected_event_reader.iter() {
+        spawn_emitter(
+            &mut commands,
+            ParticleEffect::Pickup(event.tier),
+            event.position,
+        );
+        spawn_popup(
+            &mut commands,
+            &game_assets,
+            format!("+{}", event.points),
+            POPUP_COLOR,
+            event.position,
+        );
+    }
+}
+
+pub fn spawn_near_miss_popups(
+    mut commands: Commands,
+    mut near_miss_event_reader: EventReader<NearMiss>,
+    game_assets: Res<GameAssets>,
+) {
+    for event in near_miss_event_reader.iter() {
+        spawn_popup(
+            &mut commands,
+            &game_assets,
+            format!("+{}", event.points),
+            NEAR_MISS_POPUP_COLOR,
+            event.position,
+        );
+    }
+}
+
+pub fn spawn_enemy_destroyed_effects(
+    mut commands: Commands,
+    mut enemy_destroyed_event_reader: EventReader<EnemyDestroyed>,
+    game_assets: Res<GameAssets>,
+) {
+    for event in enemy_destroyed_event_reader.iter() {
+        spawn_emitter(&mut commands, ParticleEffect::Debris, event.position);
+        spawn_popup(
+            &mut commands,
+            &game_assets,
+            format!("+{}", event.points),
+            POPUP_COLOR,
+            event.position,
+        );
+    }
+}
+
+pub fn update_emitters(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ParticleEmitter, &GlobalTransform)>,
+    game_time: Res<GameTime>,
+) {
+    let mut rng = thread_rng();
+
+    for (entity, mut emitter, transform) in query.iter_mut() {
+        emitter.duration.tick(game_time.delta());
+        emitter.accumulator += emitter.rate * game_time.delta_seconds();
+
+        let count = emitter.burst + emitter.accumulator as usize;
+        emitter.burst = 0;
+        emitter.accumulator = emitter.accumulator.fract();
+
+        let origin = transform.translation().truncate().extend(PARTICLE_Z);
+        let settings = &emitter.particle;
+        for _ in 0..count {
+            let speed = rng.gen_range(settings.speed.0..=settings.speed.1);
+            commands.spawn((
+                Particle {
+                    velocity: Vec2::from_angle(rng.gen::<f32>() * TAU) * speed,
+                    age: Timer::from_seconds(settings.lifetime, TimerMode::Once),
+                    settings: settings.clone(),
+                },
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: settings.colors.0,
+                        custom_size: Some(Vec2::splat(settings.size)),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(origin)
+                        .with_scale(Vec3::splat(settings.scales.0)),
+                    ..default()
+                },
+            ));
+        }
+
+        if emitter.duration.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn update_particles(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+    game_time: Res<GameTime>,
+) {
+    for (entity, mut particle, mut transform, mut sprite) in query.iter_mut() {
+        if particle.age.tick(game_time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let damping = (-particle.settings.damping * game_time.delta_seconds()).exp();
+        particle.velocity *= damping;
+        transform.translation += (particle.velocity * game_time.delta_seconds()).extend(0.0);
+
+        let t = particle.age.percent();
+        let (start_color, end_color) = particle.settings.colors;
+        let (start_scale, end_scale) = particle.settings.scales;
+        sprite.color = lerp_color(start_color, end_color, t);
+        transform.scale = Vec3::splat(start_scale + (end_scale - start_scale) * t);
+    }
+}
+
+pub fn update_popups(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Popup, &mut Transform, &mut Text)>,
+    game_time: Res<GameTime>,
+) {
+    for (entity, mut popup, mut transform, mut text) in query.iter_mut() {
+        if popup.age.tick(game_time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        transform.translation += (popup.velocity * game_time.delta_seconds()).extend(0.0);
+        let alpha = popup.age.percent_left();
+        for section in text.sections.iter_mut() {
+            section.style.color = popup.color.with_a(alpha);
+        }
+    }
+}
@@ -1,4 +1,124 @@
+use super::{ARROW_KEYS, DASH_COOLDOWN, DASH_DURATION, DASH_INVULNERABILITY, WASD_KEYS};
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 #[derive(Component)]
-pub struct Player {}
+pub struct Player {
+    /// Position in the local player list, which picks the sprite, controls and score slot.
+    pub index: usize,
+}
+
+impl Player {
+    pub fn label(&self) -> String {
+        format!("P{}", self.index + 1)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyBindings {
+    pub up: KeyCode,
+    pub down: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    pub dash: KeyCode,
+    pub fire: KeyCode,
+}
+
+/// Input devices a player listens to.
+#[derive(Component, Clone, Debug)]
+pub struct Controls {
+    pub keys: Vec<KeyBindings>,
+    pub gamepad: Option<usize>,
+    pub mouse: bool,
+}
+
+impl Controls {
+    /// A lone player gets every device. With company, the first two players split the
+    /// keyboard and the rest take the first two gamepads.
+    pub fn for_player(index: usize, players: usize) -> Self {
+        if players <= 1 {
+            return Self {
+                keys: vec![WASD_KEYS, ARROW_KEYS],
+                gamepad: Some(0),
+                mouse: true,
+            };
+        }
+
+        match index {
+            0 => Self {
+                keys: vec![WASD_KEYS],
+                gamepad: None,
+                mouse: true,
+            },
+            1 => Self {
+                keys: vec![ARROW_KEYS],
+                gamepad: None,
+                mouse: false,
+            },
+            _ => Self {
+                keys: Vec::new(),
+                gamepad: Some(index - 2),
+                mouse: false,
+            },
+        }
+    }
+}
+
+/// What a player asked for this frame, gathered from its `Controls`.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerInput {
+    pub movement: Vec2,
+    pub dash: bool,
+    pub fire: bool,
+    /// Direction to shoot in, when the input aims on its own rather than the way the player last moved.
+    pub aim: Option<Vec2>,
+}
+
+#[derive(Component, Default)]
+pub struct Velocity(pub Vec2);
+
+/// Short burst of speed along the last direction the player moved in, with a moment of
+/// invulnerability that outlasts the burst itself.
+#[derive(Component)]
+pub struct Dash {
+    pub direction: Vec2,
+    pub active: Timer,
+    pub invulnerable: Timer,
+    pub cooldown: Timer,
+}
+
+impl Default for Dash {
+    fn default() -> Self {
+        let finished = |seconds: f32| {
+            let mut timer = Timer::from_seconds(seconds, TimerMode::Once);
+            timer.tick(Duration::from_secs_f32(seconds));
+            timer
+        };
+        Self {
+            direction: Vec2::Y,
+            active: finished(DASH_DURATION),
+            invulnerable: finished(DASH_INVULNERABILITY),
+            cooldown: finished(DASH_COOLDOWN),
+        }
+    }
+}
+
+impl Dash {
+    pub fn is_dashing(&self) -> bool {
+        !self.active.finished()
+    }
+
+    pub fn is_invulnerable(&self) -> bool {
+        !self.invulnerable.finished()
+    }
+
+    pub fn start(&mut self) {
+        self.active.reset();
+        self.invulnerable.reset();
+        self.cooldown.reset();
+    }
+}
+
+#[derive(Component)]
+pub struct DashText {}
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+pub struct PlayerHit {
+    pub player: usize,
+    pub position: Vec3,
+}
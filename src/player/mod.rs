@@ -1,21 +1,105 @@
 use bevy::prelude::*;
 
 pub mod components;
+pub mod events;
 mod systems;
 
+use crate::{
+    animation::components::{AnimationClip, AnimationMode},
+    game::states::AppState,
+    level::resources::Level,
+};
+use components::KeyBindings;
+use events::*;
 use systems::*;
 
+pub const MAX_PLAYERS: usize = 4;
+pub const PLAYER_SPAWN_SPACING: f32 = 96.0;
+pub const GAMEPAD_AIM_THRESHOLD: f32 = 0.5;
+pub const WASD_KEYS: KeyBindings = KeyBindings {
+    up: KeyCode::W,
+    down: KeyCode::S,
+    left: KeyCode::A,
+    right: KeyCode::D,
+    dash: KeyCode::LShift,
+    fire: KeyCode::Space,
+};
+pub const ARROW_KEYS: KeyBindings = KeyBindings {
+    up: KeyCode::Up,
+    down: KeyCode::Down,
+    left: KeyCode::Left,
+    right: KeyCode::Right,
+    dash: KeyCode::RShift,
+    fire: KeyCode::RControl,
+};
 pub const PLAYER_SPEED: f32 = 500.0;
+pub const PLAYER_ACCELERATION: f32 = 3000.0;
+pub const PLAYER_FRICTION: f32 = 4.0;
 pub const PLAYER_SIZE: f32 = 64.0;
+pub const DASH_SPEED: f32 = 1800.0;
+pub const DASH_DURATION: f32 = 0.15;
+pub const DASH_INVULNERABILITY: f32 = 0.3;
+pub const DASH_COOLDOWN: f32 = 1.5;
+pub const INVULNERABLE_ALPHA: f32 = 0.5;
+pub const PLAYER_DEATH: AnimationClip = AnimationClip {
+    frames: 8,
+    fps: 16.0,
+    mode: AnimationMode::Once,
+};
+
+/// Gathers `PlayerInput`, so systems acting on it can run after.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlayerInputSet;
+
+/// Moves players, so positions can be corrected before collisions are checked.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlayerMovementSet;
+
+/// Checks what players ran into, once they are in their final place for the frame.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlayerCollisionSet;
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_startup_system(spawn_player)
-            .add_system(player_movement)
-            .add_system(confine_player_movement)
-            .add_system(player_hit_enemy)
-            .add_system(player_hit_star);
+        app.add_event::<PlayerHit>()
+            .add_systems((spawn_player, spawn_dash_text).in_schedule(OnEnter(AppState::Game)))
+            .add_system(spawn_player_death)
+            .add_systems((despawn_player, despawn_dash_text).in_schedule(OnExit(AppState::Game)))
+            .add_system(spawn_player.in_schedule(OnEnter(AppState::Editor)))
+            .add_system(despawn_player.in_schedule(OnExit(AppState::Editor)))
+            .add_systems(
+                (despawn_player, spawn_player)
+                    .chain()
+                    .distributive_run_if(resource_changed::<Level>())
+                    .in_set(OnUpdate(AppState::Editor)),
+            )
+            .add_systems(
+                (
+                    read_player_input.in_set(PlayerInputSet),
+                    start_dash.in_set(PlayerMovementSet),
+                    update_dash.in_set(PlayerMovementSet),
+                    player_movement.in_set(PlayerMovementSet),
+                    confine_player_movement.in_set(PlayerMovementSet),
+                    player_hit_enemy.in_set(PlayerCollisionSet),
+                    player_hit_star.in_set(PlayerCollisionSet),
+                )
+                    .chain()
+                    .in_set(OnUpdate(AppState::Game)),
+            )
+            .add_system(update_dash_text.in_set(OnUpdate(AppState::Game)))
+            .add_systems(
+                (
+                    read_player_input.in_set(PlayerInputSet),
+                    start_dash.in_set(PlayerMovementSet),
+                    update_dash.in_set(PlayerMovementSet),
+                    player_movement.in_set(PlayerMovementSet),
+                    confine_player_movement.in_set(PlayerMovementSet),
+                )
+                    .chain()
+                    .in_set(OnUpdate(AppState::Online)),
+            )
+            .add_system(despawn_player.in_schedule(OnExit(AppState::Online)));
     }
 }
@@ -1,140 +1,444 @@
-use super::{components::*, PLAYER_SIZE, PLAYER_SPEED};
+use super::{components::*, events::*, PLAYER_DEATH, PLAYER_SIZE, PLAYER_SPAWN_SPACING};
+use super::{DASH_SPEED, GAMEPAD_AIM_THRESHOLD, INVULNERABLE_ALPHA};
 use crate::{
+    animation::components::{Animation, DespawnOnFinish},
+    arena::resources::Arena,
+    combo::resources::Combo,
     enemy::{components::*, ENEMY_SIZE},
-    game::events::*,
+    game::{components::MainCamera, events::*, states::AppState},
+    game_time::resources::GameTime,
+    level::resources::Level,
+    loading::resources::GameAssets,
+    net::resources::NetClient,
+    particle::{components::ParticleEffect, events::SpawnParticles},
+    powerup::{
+        components::{PowerUpKind, Shield, SpeedBoost},
+        events::PowerUpExpired,
+        SPEED_BOOST_FACTOR,
+    },
     score::resources::*,
-    star::{components::*, STAR_SIZE},
+    settings::resources::Settings,
+    sound::{events::PlaySoundEffect, resources::SoundEffect},
+    star::{components::*, events::StarCollected, resources::StarPool, STAR_SIZE},
 };
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::{prelude::*, utils::HashSet, window::PrimaryWindow};
 
 pub fn spawn_player(
     mut commands: Commands,
-    query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    arena: Res<Arena>,
+    level: Res<Level>,
+    game_assets: Res<GameAssets>,
+    settings: Res<Settings>,
+    state: Res<State<AppState>>,
 ) {
-    let window = query.get_single().unwrap();
-    let width = window.width();
-    let height = window.height();
+    // The editor only needs the one marker for the start position.
+    let players = if state.0 == AppState::Editor {
+        1
+    } else {
+        settings.controls.players()
+    };
+    let start = level.player_position(&arena);
 
-    commands.spawn((
-        Player {},
-        SpriteBundle {
-            transform: Transform::from_xyz(width / 2.0, height / 2.0, 0.0),
-            texture: asset_server.load("sprites/ball_blue_large.png"),
-            ..default()
-        },
-    ));
+    for index in 0..players {
+        let offset = (index as f32 - (players - 1) as f32 * 0.5) * PLAYER_SPAWN_SPACING;
+        let position = arena.clamp(
+            (start + Vec2::new(offset, 0.0)).extend(0.0),
+            PLAYER_SIZE * 0.5,
+        );
+        commands.spawn((
+            Player { index },
+            Controls::for_player(index, players),
+            PlayerInput::default(),
+            Velocity::default(),
+            Dash::default(),
+            SpriteBundle {
+                transform: Transform::from_translation(position),
+                texture: game_assets.player_texture(index).clone(),
+                ..default()
+            },
+        ));
+    }
 }
 
-pub fn player_movement(
+pub fn spawn_player_death(
+    mut commands: Commands,
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    game_assets: Res<GameAssets>,
+) {
+    for event in player_hit_event_reader.iter() {
+        commands.spawn((
+            Animation::new(PLAYER_DEATH),
+            DespawnOnFinish {},
+            SpriteSheetBundle {
+                transform: Transform::from_translation(event.position),
+                texture_atlas: game_assets.player_death_atlas(event.player).clone(),
+                ..default()
+            },
+        ));
+    }
+}
+
+pub fn despawn_player(mut commands: Commands, query: Query<Entity, With<Player>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_player_input(
+    mut query: Query<(&Controls, &Transform, &mut PlayerInput)>,
     keyboard_input: Res<Input<KeyCode>>,
-    mut query: Query<&mut Transform, With<Player>>,
-    time: Res<Time>,
+    mouse_input: Res<Input<MouseButton>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
 ) {
-    if let Ok(mut transform) = query.get_single_mut() {
-        let mut direction = Vec3::ZERO;
+    let cursor = window_query
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .zip(camera_query.get_single().ok())
+        .and_then(|(cursor, (camera, camera_transform))| {
+            camera.viewport_to_world_2d(camera_transform, cursor)
+        });
+
+    for (controls, transform, mut input) in query.iter_mut() {
+        let mut next = PlayerInput::default();
 
-        if keyboard_input.pressed(KeyCode::Left) || keyboard_input.pressed(KeyCode::A) {
-            direction.x -= 1.0;
+        for keys in controls.keys.iter() {
+            let axis = |negative: KeyCode, positive: KeyCode| {
+                keyboard_input.pressed(positive) as i32 as f32
+                    - keyboard_input.pressed(negative) as i32 as f32
+            };
+            next.movement += Vec2::new(axis(keys.left, keys.right), axis(keys.down, keys.up));
+            next.dash |= keyboard_input.just_pressed(keys.dash);
+            next.fire |= keyboard_input.pressed(keys.fire);
         }
-        if keyboard_input.pressed(KeyCode::Right) || keyboard_input.pressed(KeyCode::D) {
-            direction.x += 1.0;
+
+        let gamepad = controls
+            .gamepad
+            .and_then(|index| gamepads.iter().nth(index));
+        if let Some(gamepad) = gamepad {
+            let axis = |axis_type| {
+                gamepad_axes
+                    .get(GamepadAxis::new(gamepad, axis_type))
+                    .unwrap_or(0.0)
+            };
+            let button = |button_type| GamepadButton::new(gamepad, button_type);
+
+            next.movement += Vec2::new(
+                axis(GamepadAxisType::LeftStickX),
+                axis(GamepadAxisType::LeftStickY),
+            );
+            next.dash |= gamepad_buttons.just_pressed(button(GamepadButtonType::South));
+            next.fire |= gamepad_buttons.pressed(button(GamepadButtonType::RightTrigger2));
+
+            let stick = Vec2::new(
+                axis(GamepadAxisType::RightStickX),
+                axis(GamepadAxisType::RightStickY),
+            );
+            if stick.length() > GAMEPAD_AIM_THRESHOLD {
+                next.fire = true;
+                next.aim = Some(stick.normalize());
+            }
         }
-        if keyboard_input.pressed(KeyCode::Down) || keyboard_input.pressed(KeyCode::S) {
-            direction.y -= 1.0;
+
+        if controls.mouse && mouse_input.pressed(MouseButton::Left) {
+            if let Some(aim) = cursor
+                .and_then(|target| (target - transform.translation.truncate()).try_normalize())
+            {
+                next.fire = true;
+                next.aim = Some(aim);
+            }
         }
-        if keyboard_input.pressed(KeyCode::Up) || keyboard_input.pressed(KeyCode::W) {
-            direction.y += 1.0;
+
+        next.movement = next.movement.clamp_length_max(1.0);
+        if *input != next {
+            *input = next;
         }
+    }
+}
+
+pub fn start_dash(
+    mut commands: Commands,
+    mut query: Query<(Entity, &PlayerInput, &mut Dash), With<Player>>,
+    game_time: Res<GameTime>,
+) {
+    if game_time.is_paused() {
+        return;
+    }
 
-        if direction.length() > 0.0 {
-            direction = direction.normalize();
+    for (entity, input, mut dash) in query.iter_mut() {
+        if !input.dash || !dash.cooldown.finished() {
+            continue;
         }
+        dash.start();
 
-        transform.translation += direction * PLAYER_SPEED * time.delta_seconds();
+        // The trail emitter rides along as a child and despawns itself when the dash ends.
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                ParticleEffect::DashTrail.emitter(),
+                SpatialBundle::default(),
+            ));
+        });
     }
 }
 
-pub fn confine_player_movement(
-    mut player_query: Query<&mut Transform, With<Player>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+pub fn update_dash(mut query: Query<(&mut Dash, &mut Sprite)>, game_time: Res<GameTime>) {
+    for (mut dash, mut sprite) in query.iter_mut() {
+        dash.active.tick(game_time.delta());
+        dash.invulnerable.tick(game_time.delta());
+        dash.cooldown.tick(game_time.delta());
+
+        let alpha = if dash.is_invulnerable() {
+            INVULNERABLE_ALPHA
+        } else {
+            1.0
+        };
+        if sprite.color.a() != alpha {
+            sprite.color.set_a(alpha);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn player_movement(
+    mut query: Query<
+        (
+            &PlayerInput,
+            &mut Transform,
+            &mut Velocity,
+            &mut Dash,
+            Option<&SpeedBoost>,
+        ),
+        With<Player>,
+    >,
+    settings: Res<Settings>,
+    client: Option<Res<NetClient>>,
+    game_time: Res<GameTime>,
 ) {
-    if let Ok(mut transform) = player_query.get_single_mut() {
-        let window = window_query.get_single().unwrap();
-        let width = window.width();
-        let height = window.height();
-
-        let half_player_size = PLAYER_SIZE * 0.5;
-        let x_min = half_player_size;
-        let x_max = width - half_player_size;
-        let y_min = half_player_size;
-        let y_max = height - half_player_size;
-
-        if transform.translation.x < x_min {
-            transform.translation.x = x_min;
-        } else if transform.translation.x > x_max {
-            transform.translation.x = x_max;
+    let delta = game_time.delta_seconds();
+    // Online, the local player is predicted with the host's handling rather than our own.
+    let controls = client
+        .as_ref()
+        .and_then(|client| client.controls.as_ref())
+        .unwrap_or(&settings.controls);
+
+    for (input, mut transform, mut velocity, mut dash, speed_boost) in query.iter_mut() {
+        if let Some(direction) = input.movement.try_normalize() {
+            dash.direction = direction;
         }
-        if transform.translation.y < y_min {
-            transform.translation.y = y_min;
-        } else if transform.translation.y > y_max {
-            transform.translation.y = y_max;
+
+        let speed_factor = if speed_boost.is_some() {
+            SPEED_BOOST_FACTOR
+        } else {
+            1.0
+        };
+
+        velocity.0 = if dash.is_dashing() {
+            dash.direction * DASH_SPEED
+        } else {
+            controls.steer(velocity.0, input.movement, speed_factor, delta)
+        };
+        transform.translation += velocity.0.extend(0.0) * delta;
+    }
+}
+
+pub fn confine_player_movement(
+    mut player_query: Query<(&mut Transform, &mut Velocity), With<Player>>,
+    arena: Res<Arena>,
+) {
+    for (mut transform, mut velocity) in player_query.iter_mut() {
+        let confined = arena.confine(transform.translation, PLAYER_SIZE * 0.5);
+
+        // Stop drifting into a wall rather than pressing against it.
+        if !arena.wraps() {
+            if confined.x != transform.translation.x {
+                velocity.0.x = 0.0;
+            }
+            if confined.y != transform.translation.y {
+                velocity.0.y = 0.0;
+            }
         }
+        transform.translation = confined;
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn player_hit_enemy(
     mut commands: Commands,
     mut game_over_event_writer: EventWriter<GameOver>,
-    mut player_query: Query<(Entity, &Transform), With<Player>>,
-    enemy_query: Query<&Transform, With<Enemy>>,
-    audio: Res<Audio>,
-    asset_server: Res<AssetServer>,
+    player_query: Query<(Entity, &Player, &Transform, &Dash, Option<&Shield>)>,
+    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    mut play_sound_event_writer: EventWriter<PlaySoundEffect>,
+    mut spawn_particles_event_writer: EventWriter<SpawnParticles>,
+    mut player_hit_event_writer: EventWriter<PlayerHit>,
+    mut power_up_expired_event_writer: EventWriter<PowerUpExpired>,
+    arena: Res<Arena>,
     score: Res<Score>,
 ) {
     let collision_distance = (PLAYER_SIZE + ENEMY_SIZE) * 0.5;
+    let mut survivors = player_query.iter().len();
+    let mut destroyed = HashSet::new();
 
-    if let Ok((player_entity, player_transform)) = player_query.get_single_mut() {
-        for enemy_transform in enemy_query.iter() {
-            if player_transform
-                .translation
-                .distance(enemy_transform.translation)
-                < collision_distance
-            {
-                commands.entity(player_entity).despawn();
-                let effect = asset_server.load("audio/explosion_crunch_000.ogg");
-                audio.play(effect);
+    for (player_entity, player, player_transform, dash, shield) in player_query.iter() {
+        if dash.is_invulnerable() {
+            continue;
+        }
 
-                game_over_event_writer.send(GameOver(score.0));
-            }
+        let hit = enemy_query.iter().find(|(enemy_entity, enemy_transform)| {
+            !destroyed.contains(enemy_entity)
+                && arena.distance(player_transform.translation, enemy_transform.translation)
+                    < collision_distance
+        });
+        let Some((enemy_entity, enemy_transform)) = hit else {
+            continue;
+        };
+
+        // A shield takes the hit instead, and the enemy goes down with it.
+        if shield.is_some() {
+            destroyed.insert(enemy_entity);
+            commands.entity(player_entity).remove::<Shield>();
+            commands.entity(enemy_entity).despawn_recursive();
+            spawn_particles_event_writer.send(SpawnParticles {
+                effect: ParticleEffect::Debris,
+                position: enemy_transform.translation,
+            });
+            play_sound_event_writer.send(PlaySoundEffect {
+                effect: SoundEffect::Bounce,
+                position: Some(enemy_transform.translation),
+            });
+            power_up_expired_event_writer.send(PowerUpExpired {
+                kind: PowerUpKind::Shield,
+            });
+            continue;
+        }
+
+        commands.entity(player_entity).despawn_recursive();
+        play_sound_event_writer.send(PlaySoundEffect {
+            effect: SoundEffect::Explosion,
+            position: Some(player_transform.translation),
+        });
+        spawn_particles_event_writer.send(SpawnParticles {
+            effect: ParticleEffect::Explosion,
+            position: player_transform.translation,
+        });
+        player_hit_event_writer.send(PlayerHit {
+            player: player.index,
+            position: player_transform.translation,
+        });
+
+        survivors -= 1;
+        if survivors == 0 {
+            game_over_event_writer.send(GameOver(score.0));
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn player_hit_star(
     mut commands: Commands,
-    player_query: Query<&Transform, With<Player>>,
-    star_query: Query<(Entity, &Transform), With<Star>>,
-    audio: Res<Audio>,
-    asset_server: Res<AssetServer>,
+    player_query: Query<(&Player, &Transform)>,
+    star_query: Query<(Entity, &Transform, &Star)>,
+    mut play_sound_event_writer: EventWriter<PlaySoundEffect>,
+    arena: Res<Arena>,
     mut score: ResMut<Score>,
+    mut player_scores: ResMut<PlayerScores>,
+    mut star_pool: ResMut<StarPool>,
+    mut star_collected_event_writer: EventWriter<StarCollected>,
+    combo: Res<Combo>,
 ) {
-    if let Ok(player_transform) = player_query.get_single() {
-        let collision_distance = PLAYER_SIZE * 0.5 + STAR_SIZE * 0.5;
-
-        for (star, star_transform) in star_query.iter() {
-            if player_transform
-                .translation
-                .distance(star_transform.translation)
-                < collision_distance
+    let collision_distance = PLAYER_SIZE * 0.5 + STAR_SIZE * 0.5;
+    let mut collected = HashSet::new();
+
+    for (player, player_transform) in player_query.iter() {
+        for (star_entity, star_transform, star) in star_query.iter() {
+            if collected.contains(&star_entity)
+                || arena.distance(player_transform.translation, star_transform.translation)
+                    >= collision_distance
             {
-                score.0 += 1;
+                continue;
+            }
+            collected.insert(star_entity);
+
+            let points = combo.points(star.tier.points());
+            score.0 += points;
+            player_scores.add(player.index, points);
+            star_collected_event_writer.send(StarCollected {
+                tier: star.tier,
+                points,
+                position: star_transform.translation,
+            });
+
+            star_pool.release(&mut commands, star_entity);
+
+            play_sound_event_writer.send(PlaySoundEffect {
+                effect: SoundEffect::Pickup,
+                position: Some(star_transform.translation),
+            });
+        }
+    }
+}
+
+pub fn spawn_dash_text(mut commands: Commands, game_assets: Res<GameAssets>) {
+    commands.spawn((
+        DashText {},
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: game_assets.font.clone(),
+                font_size: 24.0,
+                color: Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                right: Val::Px(10.0),
+                bottom: Val::Px(10.0),
+                ..default()
+            },
+            ..default()
+        }),
+    ));
+}
 
-                commands.entity(star).despawn();
+pub fn despawn_dash_text(mut commands: Commands, query: Query<Entity, With<DashText>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn update_dash_text(
+    mut text_query: Query<&mut Text, With<DashText>>,
+    dash_query: Query<(&Player, &Dash)>,
+    settings: Res<Settings>,
+) {
+    let mut players = dash_query.iter().collect::<Vec<_>>();
+    players.sort_by_key(|(player, _)| player.index);
 
-                let effect = asset_server.load("audio/laser_large_000.ogg");
-                audio.play(effect);
+    let value = players
+        .iter()
+        .map(|(player, dash)| {
+            let status = if dash.cooldown.finished() {
+                "ready".to_string()
+            } else {
+                format!("{:.1}s", dash.cooldown.remaining_secs())
+            };
+            if settings.controls.players() > 1 {
+                format!("{} dash {}", player.label(), status)
+            } else {
+                format!("Dash {}", status)
             }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    for mut text in text_query.iter_mut() {
+        if text.sections[0].value != value {
+            text.sections[0].value = value.clone();
         }
     }
 }
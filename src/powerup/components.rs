@@ -0,0 +1,143 @@
+use super::{MAGNET_DURATION, SHIELD_DURATION, SLOW_MOTION_DURATION, SPEED_BOOST_DURATION};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PowerUpKind {
+    Shield,
+    SlowMotion,
+    Magnet,
+    SpeedBoost,
+}
+
+impl PowerUpKind {
+    pub const ALL: [PowerUpKind; 4] = [
+        PowerUpKind::Shield,
+        PowerUpKind::SlowMotion,
+        PowerUpKind::Magnet,
+        PowerUpKind::SpeedBoost,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PowerUpKind::Shield => "Shield",
+            PowerUpKind::SlowMotion => "Slow-mo",
+            PowerUpKind::Magnet => "Magnet",
+            PowerUpKind::SpeedBoost => "Speed",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            PowerUpKind::Shield => Color::rgb(0.4, 0.8, 1.0),
+            PowerUpKind::SlowMotion => Color::rgb(0.7, 0.5, 1.0),
+            PowerUpKind::Magnet => Color::rgb(1.0, 0.4, 0.4),
+            PowerUpKind::SpeedBoost => Color::rgb(0.5, 1.0, 0.5),
+        }
+    }
+}
+
+/// A pickup lying in the arena.
+#[derive(Component)]
+pub struct PowerUp {
+    pub kind: PowerUpKind,
+}
+
+/// An effect attached to the player that runs out after a while.
+pub trait TimedPowerUp: Component {
+    const KIND: PowerUpKind;
+
+    fn new() -> Self;
+    fn timer(&self) -> &Timer;
+    fn timer_mut(&mut self) -> &mut Timer;
+}
+
+/// Absorbs one enemy hit.
+#[derive(Component)]
+pub struct Shield {
+    pub timer: Timer,
+}
+
+impl TimedPowerUp for Shield {
+    const KIND: PowerUpKind = PowerUpKind::Shield;
+
+    fn new() -> Self {
+        Self {
+            timer: Timer::from_seconds(SHIELD_DURATION, TimerMode::Once),
+        }
+    }
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+/// Slows every enemy down while the player holds it.
+#[derive(Component)]
+pub struct SlowMotion {
+    pub timer: Timer,
+}
+
+impl TimedPowerUp for SlowMotion {
+    const KIND: PowerUpKind = PowerUpKind::SlowMotion;
+
+    fn new() -> Self {
+        Self {
+            timer: Timer::from_seconds(SLOW_MOTION_DURATION, TimerMode::Once),
+        }
+    }
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+/// Pulls nearby stars towards the player.
+#[derive(Component)]
+pub struct Magnet {
+    pub timer: Timer,
+}
+
+impl TimedPowerUp for Magnet {
+    const KIND: PowerUpKind = PowerUpKind::Magnet;
+
+    fn new() -> Self {
+        Self {
+            timer: Timer::from_seconds(MAGNET_DURATION, TimerMode::Once),
+        }
+    }
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+#[derive(Component)]
+pub struct SpeedBoost {
+    pub timer: Timer,
+}
+
+impl TimedPowerUp for SpeedBoost {
+    const KIND: PowerUpKind = PowerUpKind::SpeedBoost;
+
+    fn new() -> Self {
+        Self {
+            timer: Timer::from_seconds(SPEED_BOOST_DURATION, TimerMode::Once),
+        }
+    }
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+#[derive(Component)]
+pub struct PowerUpText {}
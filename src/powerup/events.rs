@@ -0,0 +1,12 @@
+use super::components::PowerUpKind;
+use bevy::prelude::*;
+
+pub struct PowerUpCollected {
+    pub kind: PowerUpKind,
+    pub position: Vec3,
+}
+
+/// Sent when an effect runs out or, for the shield, is used up.
+pub struct PowerUpExpired {
+    pub kind: PowerUpKind,
+}
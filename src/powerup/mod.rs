@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+use crate::{enemy::EnemyMovementSet, game::states::AppState};
+use components::*;
+use events::*;
+use resources::*;
+use systems::*;
+
+pub const POWER_UP_SIZE: f32 = 28.0;
+pub const POWER_UP_SPAWN_INTERVAL: f32 = 12.0;
+pub const POWER_UP_SPAWN_ATTEMPTS: usize = 16;
+pub const MAX_POWER_UPS: usize = 2;
+pub const SHIELD_DURATION: f32 = 10.0;
+pub const SLOW_MOTION_DURATION: f32 = 6.0;
+pub const SLOW_MOTION_FACTOR: f32 = 0.4;
+pub const MAGNET_DURATION: f32 = 8.0;
+pub const MAGNET_RADIUS: f32 = 250.0;
+pub const MAGNET_SPEED: f32 = 400.0;
+pub const SPEED_BOOST_DURATION: f32 = 6.0;
+pub const SPEED_BOOST_FACTOR: f32 = 1.6;
+
+pub struct PowerUpPlugin;
+
+impl Plugin for PowerUpPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PowerUpSpawnTimer>()
+            .add_event::<PowerUpCollected>()
+            .add_event::<PowerUpExpired>()
+            .add_systems(
+                (reset_power_up_spawn_timer, spawn_power_up_text)
+                    .in_schedule(OnEnter(AppState::Game)),
+            )
+            .add_systems(
+                (despawn_power_ups, despawn_power_up_text).in_schedule(OnExit(AppState::Game)),
+            )
+            .add_systems(
+                (
+                    tick_power_up_spawn_timer,
+                    spawn_power_ups_over_time,
+                    player_hit_power_up,
+                    expire_power_up::<Shield>,
+                    expire_power_up::<SlowMotion>,
+                    expire_power_up::<Magnet>,
+                    expire_power_up::<SpeedBoost>,
+                    attract_stars,
+                    update_power_up_text,
+                )
+                    .in_set(OnUpdate(AppState::Game)),
+            )
+            .add_system(
+                apply_slow_motion
+                    .before(EnemyMovementSet)
+                    .in_set(OnUpdate(AppState::Game)),
+            );
+    }
+}
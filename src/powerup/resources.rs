@@ -0,0 +1,13 @@
+use super::POWER_UP_SPAWN_INTERVAL;
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct PowerUpSpawnTimer(pub Timer);
+impl Default for PowerUpSpawnTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(
+            POWER_UP_SPAWN_INTERVAL,
+            TimerMode::Repeating,
+        ))
+    }
+}
@@ -0,0 +1,252 @@
+use super::SLOW_MOTION_FACTOR;
+use super::{components::*, events::*, resources::*};
+use super::{MAGNET_RADIUS, MAGNET_SPEED, MAX_POWER_UPS, POWER_UP_SIZE, POWER_UP_SPAWN_ATTEMPTS};
+use crate::{
+    arena::resources::Arena,
+    game_time::resources::GameTime,
+    level::resources::Level,
+    loading::resources::GameAssets,
+    player::{components::Player, PLAYER_SIZE},
+    settings::resources::Settings,
+    sound::{events::PlaySoundEffect, resources::SoundEffect},
+    star::components::Star,
+};
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::f32::consts::FRAC_PI_4;
+
+pub fn reset_power_up_spawn_timer(mut power_up_spawn_timer: ResMut<PowerUpSpawnTimer>) {
+    *power_up_spawn_timer = PowerUpSpawnTimer::default();
+}
+
+pub fn tick_power_up_spawn_timer(
+    mut power_up_spawn_timer: ResMut<PowerUpSpawnTimer>,
+    game_time: Res<GameTime>,
+) {
+    power_up_spawn_timer.0.tick(game_time.delta());
+}
+
+pub fn spawn_power_ups_over_time(
+    mut commands: Commands,
+    arena: Res<Arena>,
+    level: Res<Level>,
+    power_up_spawn_timer: Res<PowerUpSpawnTimer>,
+    power_up_query: Query<(), With<PowerUp>>,
+) {
+    if !power_up_spawn_timer.0.just_finished() || power_up_query.iter().count() >= MAX_POWER_UPS {
+        return;
+    }
+
+    let position = (0..POWER_UP_SPAWN_ATTEMPTS)
+        .map(|_| arena.random_position())
+        .find(|position| !level.is_blocked(*position, POWER_UP_SIZE * 0.5));
+    let Some(position) = position else {
+        return;
+    };
+    let kind = *PowerUpKind::ALL
+        .choose(&mut thread_rng())
+        .unwrap_or(&PowerUpKind::Shield);
+
+    commands.spawn((
+        PowerUp { kind },
+        SpriteBundle {
+            sprite: Sprite {
+                color: kind.color(),
+                custom_size: Some(Vec2::splat(POWER_UP_SIZE)),
+                ..default()
+            },
+            transform: Transform::from_translation(position.extend(-0.5))
+                .with_rotation(Quat::from_rotation_z(FRAC_PI_4)),
+            ..default()
+        },
+    ));
+}
+
+pub fn despawn_power_ups(mut commands: Commands, query: Query<Entity, With<PowerUp>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn player_hit_power_up(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Transform), With<Player>>,
+    power_up_query: Query<(Entity, &Transform, &PowerUp)>,
+    mut power_up_collected_event_writer: EventWriter<PowerUpCollected>,
+    mut play_sound_event_writer: EventWriter<PlaySoundEffect>,
+    arena: Res<Arena>,
+) {
+    let collision_distance = (PLAYER_SIZE + POWER_UP_SIZE) * 0.5;
+
+    for (entity, transform, power_up) in power_up_query.iter() {
+        let picked_by = player_query.iter().find(|(_, player_transform)| {
+            arena.distance(player_transform.translation, transform.translation) < collision_distance
+        });
+        let Some((player_entity, _)) = picked_by else {
+            continue;
+        };
+
+        // Inserting over an active effect restarts its timer.
+        let mut player = commands.entity(player_entity);
+        match power_up.kind {
+            PowerUpKind::Shield => player.insert(Shield::new()),
+            PowerUpKind::SlowMotion => player.insert(SlowMotion::new()),
+            PowerUpKind::Magnet => player.insert(Magnet::new()),
+            PowerUpKind::SpeedBoost => player.insert(SpeedBoost::new()),
+        };
+        commands.entity(entity).despawn_recursive();
+
+        power_up_collected_event_writer.send(PowerUpCollected {
+            kind: power_up.kind,
+            position: transform.translation,
+        });
+        play_sound_event_writer.send(PlaySoundEffect {
+            effect: SoundEffect::Pickup,
+            position: Some(transform.translation),
+        });
+    }
+}
+
+pub fn expire_power_up<T: TimedPowerUp>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut T)>,
+    mut power_up_expired_event_writer: EventWriter<PowerUpExpired>,
+    game_time: Res<GameTime>,
+) {
+    for (entity, mut power_up) in query.iter_mut() {
+        if power_up.timer_mut().tick(game_time.delta()).just_finished() {
+            commands.entity(entity).remove::<T>();
+            power_up_expired_event_writer.send(PowerUpExpired { kind: T::KIND });
+        }
+    }
+}
+
+pub fn attract_stars(
+    player_query: Query<&Transform, (With<Player>, With<Magnet>)>,
+    mut star_query: Query<&mut Transform, (With<Star>, Without<Player>)>,
+    arena: Res<Arena>,
+    game_time: Res<GameTime>,
+) {
+    for mut transform in star_query.iter_mut() {
+        // Each star follows the closest magnet in range.
+        let pull = player_query
+            .iter()
+            .map(|player_transform| {
+                arena.delta(transform.translation, player_transform.translation)
+            })
+            .filter(|delta| delta.length() <= MAGNET_RADIUS)
+            .min_by(|a, b| a.length().total_cmp(&b.length()));
+        let Some(delta) = pull else {
+            continue;
+        };
+
+        let distance = delta.length();
+        if distance == 0.0 {
+            continue;
+        }
+        let step = (MAGNET_SPEED * game_time.delta_seconds()).min(distance);
+        transform.translation += (delta / distance * step).extend(0.0);
+    }
+}
+
+pub fn spawn_power_up_text(mut commands: Commands, game_assets: Res<GameAssets>) {
+    commands.spawn((
+        PowerUpText {},
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: game_assets.font.clone(),
+                font_size: 24.0,
+                color: Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                right: Val::Px(10.0),
+                top: Val::Px(50.0),
+                ..default()
+            },
+            ..default()
+        }),
+    ));
+}
+
+pub fn despawn_power_up_text(mut commands: Commands, query: Query<Entity, With<PowerUpText>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn power_up_section<T: TimedPowerUp>(
+    power_up: Option<&T>,
+    label: &str,
+    style: &TextStyle,
+) -> Option<TextSection> {
+    let power_up = power_up?;
+    Some(TextSection::new(
+        format!(
+            "{}{} {:.1}s\n",
+            label,
+            T::KIND.name(),
+            power_up.timer().remaining_secs()
+        ),
+        TextStyle {
+            color: T::KIND.color(),
+            ..style.clone()
+        },
+    ))
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_power_up_text(
+    mut text_query: Query<&mut Text, With<PowerUpText>>,
+    player_query: Query<(
+        &Player,
+        Option<&Shield>,
+        Option<&SlowMotion>,
+        Option<&Magnet>,
+        Option<&SpeedBoost>,
+    )>,
+    settings: Res<Settings>,
+) {
+    let mut players = player_query.iter().collect::<Vec<_>>();
+    players.sort_by_key(|(player, ..)| player.index);
+
+    for mut text in text_query.iter_mut() {
+        let style = text.sections[0].style.clone();
+        let mut sections = Vec::new();
+        for (player, shield, slow_motion, magnet, speed_boost) in players.iter() {
+            let label = if settings.controls.players() > 1 {
+                format!("{} ", player.label())
+            } else {
+                String::new()
+            };
+            sections.extend([
+                power_up_section(*shield, &label, &style),
+                power_up_section(*slow_motion, &label, &style),
+                power_up_section(*magnet, &label, &style),
+                power_up_section(*speed_boost, &label, &style),
+            ]);
+        }
+
+        // Keep one section around to carry the style for the next update.
+        text.sections = sections.into_iter().flatten().collect();
+        if text.sections.is_empty() {
+            text.sections.push(TextSection::new("", style));
+        }
+    }
+}
+
+/// Slows enemies, and only enemies, while any player holds slow-mo.
+pub fn apply_slow_motion(
+    slow_motion_query: Query<(), With<SlowMotion>>,
+    mut game_time: ResMut<GameTime>,
+) {
+    let factor = if slow_motion_query.is_empty() {
+        1.0
+    } else {
+        SLOW_MOTION_FACTOR
+    };
+    game_time.set_enemy_scale(factor);
+}
@@ -0,0 +1,24 @@
+use super::FIRE_COOLDOWN;
+use bevy::prelude::*;
+use std::time::Duration;
+
+#[derive(Component)]
+pub struct Projectile {
+    pub velocity: Vec2,
+    /// `Player::index` of the shooter, who gets the points.
+    pub owner: usize,
+}
+
+/// Fire-rate limiter for a player.
+#[derive(Component)]
+pub struct Weapon {
+    pub cooldown: Timer,
+}
+
+impl Default for Weapon {
+    fn default() -> Self {
+        let mut cooldown = Timer::from_seconds(FIRE_COOLDOWN, TimerMode::Once);
+        cooldown.tick(Duration::from_secs_f32(FIRE_COOLDOWN));
+        Self { cooldown }
+    }
+}
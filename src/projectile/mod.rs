@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod systems;
+
+use crate::{game::states::AppState, player::PlayerInputSet};
+use systems::*;
+
+pub const PROJECTILE_SPEED: f32 = 900.0;
+pub const PROJECTILE_SIZE: f32 = 10.0;
+pub const PROJECTILE_COLOR: Color = Color::rgb(0.6, 0.9, 1.0);
+pub const FIRE_COOLDOWN: f32 = 0.2;
+pub const ENEMY_KILL_POINTS: u32 = 3;
+
+pub struct ProjectilePlugin;
+
+impl Plugin for ProjectilePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(despawn_projectiles.in_schedule(OnExit(AppState::Game)))
+            .add_systems(
+                (
+                    equip_weapon,
+                    fire_projectiles,
+                    move_projectiles,
+                    projectile_hit_enemy,
+                )
+                    .chain()
+                    .after(PlayerInputSet)
+                    .in_set(OnUpdate(AppState::Game)),
+            );
+    }
+}
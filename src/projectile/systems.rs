@@ -0,0 +1,143 @@
+use super::{
+    components::*, ENEMY_KILL_POINTS, PROJECTILE_COLOR, PROJECTILE_SIZE, PROJECTILE_SPEED,
+};
+use crate::{
+    arena::resources::Arena,
+    combo::resources::Combo,
+    enemy::{components::Enemy, events::EnemyDestroyed, ENEMY_SIZE},
+    game_time::resources::GameTime,
+    player::{
+        components::{Dash, Player, PlayerInput},
+        PLAYER_SIZE,
+    },
+    score::resources::{PlayerScores, Score},
+    sound::{events::PlaySoundEffect, resources::SoundEffect},
+};
+use bevy::{prelude::*, utils::HashSet};
+
+pub fn equip_weapon(mut commands: Commands, query: Query<Entity, Added<Player>>) {
+    for entity in query.iter() {
+        commands.entity(entity).insert(Weapon::default());
+    }
+}
+
+pub fn fire_projectiles(
+    mut commands: Commands,
+    mut player_query: Query<(&Player, &PlayerInput, &Dash, &Transform, &mut Weapon)>,
+    mut play_sound_event_writer: EventWriter<PlaySoundEffect>,
+    game_time: Res<GameTime>,
+) {
+    if game_time.is_paused() {
+        return;
+    }
+
+    for (player, input, dash, transform, mut weapon) in player_query.iter_mut() {
+        weapon.cooldown.tick(game_time.delta());
+
+        let origin = transform.translation.truncate();
+        let direction = match input.aim {
+            Some(aim) if input.fire => Some(aim),
+            // Without an aim of their own, shots go the way the player last moved.
+            None if input.fire => Some(dash.direction),
+            _ => None,
+        };
+
+        let Some(direction) = direction else {
+            continue;
+        };
+        if !weapon.cooldown.finished() {
+            continue;
+        }
+        weapon.cooldown.reset();
+
+        let position = origin + direction * PLAYER_SIZE * 0.5;
+        commands.spawn((
+            Projectile {
+                velocity: direction * PROJECTILE_SPEED,
+                owner: player.index,
+            },
+            SpriteBundle {
+                sprite: Sprite {
+                    color: PROJECTILE_COLOR,
+                    custom_size: Some(Vec2::splat(PROJECTILE_SIZE)),
+                    ..default()
+                },
+                transform: Transform::from_translation(position.extend(0.5)),
+                ..default()
+            },
+        ));
+        play_sound_event_writer.send(PlaySoundEffect {
+            effect: SoundEffect::Shot,
+            position: Some(position.extend(0.0)),
+        });
+    }
+}
+
+pub fn move_projectiles(
+    mut commands: Commands,
+    mut query: Query<(Entity, &Projectile, &mut Transform)>,
+    arena: Res<Arena>,
+    game_time: Res<GameTime>,
+) {
+    let (min, max) = arena.bounds(-PROJECTILE_SIZE);
+
+    for (entity, projectile, mut transform) in query.iter_mut() {
+        transform.translation += (projectile.velocity * game_time.delta_seconds()).extend(0.0);
+
+        let position = transform.translation.truncate();
+        if position.clamp(min, max) != position {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn projectile_hit_enemy(
+    mut commands: Commands,
+    projectile_query: Query<(Entity, &Projectile, &Transform)>,
+    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    mut enemy_destroyed_event_writer: EventWriter<EnemyDestroyed>,
+    mut play_sound_event_writer: EventWriter<PlaySoundEffect>,
+    mut score: ResMut<Score>,
+    mut player_scores: ResMut<PlayerScores>,
+    combo: Res<Combo>,
+    arena: Res<Arena>,
+) {
+    let collision_distance = (PROJECTILE_SIZE + ENEMY_SIZE) * 0.5;
+    let mut destroyed = HashSet::new();
+
+    for (projectile_entity, projectile, projectile_transform) in projectile_query.iter() {
+        let hit = enemy_query.iter().find(|(enemy, enemy_transform)| {
+            !destroyed.contains(enemy)
+                && arena.distance(
+                    projectile_transform.translation,
+                    enemy_transform.translation,
+                ) < collision_distance
+        });
+        let Some((enemy, enemy_transform)) = hit else {
+            continue;
+        };
+        destroyed.insert(enemy);
+
+        commands.entity(projectile_entity).despawn_recursive();
+        commands.entity(enemy).despawn_recursive();
+
+        let points = combo.points(ENEMY_KILL_POINTS);
+        score.0 += points;
+        player_scores.add(projectile.owner, points);
+        enemy_destroyed_event_writer.send(EnemyDestroyed {
+            points,
+            position: enemy_transform.translation,
+        });
+        play_sound_event_writer.send(PlaySoundEffect {
+            effect: SoundEffect::Explosion,
+            position: Some(enemy_transform.translation),
+        });
+    }
+}
+
+pub fn despawn_projectiles(mut commands: Commands, query: Query<Entity, With<Projectile>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -3,6 +3,7 @@ use bevy::prelude::*;
 pub mod resources;
 mod systems;
 
+use crate::game::states::AppState;
 use resources::*;
 use systems::*;
 
@@ -11,7 +12,9 @@ pub struct ScorePlugin;
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Score>()
+            .init_resource::<PlayerScores>()
             .init_resource::<HighScores>()
+            .add_system(reset_score.in_schedule(OnEnter(AppState::Game)))
             .add_system(update_score)
             .add_system(update_high_scores)
             .add_system(high_scores_updated);
@@ -8,6 +8,19 @@ impl Default for Score {
     }
 }
 
+/// Points earned by each local player, indexed like `Player::index`. `Score` holds the total.
+#[derive(Resource, Default)]
+pub struct PlayerScores(pub Vec<u32>);
+
+impl PlayerScores {
+    pub fn add(&mut self, player: usize, points: u32) {
+        if self.0.len() <= player {
+            self.0.resize(player + 1, 0);
+        }
+        self.0[player] += points;
+    }
+}
+
 #[derive(Resource)]
 pub struct HighScores(pub Vec<(String, u32)>);
 impl Default for HighScores {
@@ -1,7 +1,16 @@
 use super::resources::*;
-use crate::game::events::*;
+use crate::{game::events::*, settings::resources::Settings};
 use bevy::prelude::*;
 
+pub fn reset_score(
+    mut score: ResMut<Score>,
+    mut player_scores: ResMut<PlayerScores>,
+    settings: Res<Settings>,
+) {
+    score.0 = 0;
+    player_scores.0 = vec![0; settings.controls.players()];
+}
+
 pub fn update_score(score: Res<Score>) {
     if score.is_changed() {
         println!("Score: {}", score.0);
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+pub const SETTINGS_PATH: &str = "settings.ron";
+pub const VOLUME_STEP: f32 = 0.1;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Settings::load(SETTINGS_PATH))
+            .add_system(toggle_mute)
+            .add_system(adjust_master_volume)
+            .add_system(toggle_reduced_motion)
+            .add_system(toggle_handling)
+            .add_system(
+                save_settings
+                    .after(toggle_mute)
+                    .after(adjust_master_volume)
+                    .after(toggle_reduced_motion)
+                    .after(toggle_handling),
+            );
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    arena::{resources::EdgeMode, ARENA_HEIGHT, ARENA_WIDTH},
+    player::{MAX_PLAYERS, PLAYER_ACCELERATION, PLAYER_FRICTION, PLAYER_SPEED},
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioSettings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub effects_volume: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 0.6,
+            effects_volume: 1.0,
+            muted: false,
+        }
+    }
+}
+
+impl AudioSettings {
+    pub fn music_level(&self) -> f32 {
+        self.level(self.music_volume)
+    }
+
+    pub fn effects_level(&self) -> f32 {
+        self.level(self.effects_volume)
+    }
+
+    fn level(&self, volume: f32) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            (self.master_volume * volume).clamp(0.0, 1.0)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorldSettings {
+    pub width: f32,
+    pub height: f32,
+    pub edges: EdgeMode,
+}
+
+impl Default for WorldSettings {
+    fn default() -> Self {
+        Self {
+            width: ARENA_WIDTH,
+            height: ARENA_HEIGHT,
+            edges: EdgeMode::Bounded,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EffectsSettings {
+    pub shake_intensity: f32,
+    pub hit_stop: bool,
+    /// Accessibility switch that turns off screen shake and hit-stop regardless of the above.
+    pub reduced_motion: bool,
+}
+
+impl Default for EffectsSettings {
+    fn default() -> Self {
+        Self {
+            shake_intensity: 1.0,
+            hit_stop: true,
+            reduced_motion: false,
+        }
+    }
+}
+
+impl EffectsSettings {
+    pub fn shake_intensity(&self) -> f32 {
+        if self.reduced_motion {
+            0.0
+        } else {
+            self.shake_intensity.max(0.0)
+        }
+    }
+
+    pub fn hit_stop_enabled(&self) -> bool {
+        self.hit_stop && !self.reduced_motion
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HandlingMode {
+    /// The ship moves at full speed while a key is held and stops the moment it is released.
+    Arcade,
+    /// The ship accelerates towards the held direction and drifts to a stop under friction.
+    Inertial,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ControlSettings {
+    /// Number of local players, from 1 to `MAX_PLAYERS`.
+    pub players: usize,
+    pub handling: HandlingMode,
+    pub acceleration: f32,
+    pub friction: f32,
+    pub max_speed: f32,
+}
+
+impl Default for ControlSettings {
+    fn default() -> Self {
+        Self {
+            players: 1,
+            handling: HandlingMode::Arcade,
+            acceleration: PLAYER_ACCELERATION,
+            friction: PLAYER_FRICTION,
+            max_speed: PLAYER_SPEED,
+        }
+    }
+}
+
+impl ControlSettings {
+    pub fn players(&self) -> usize {
+        self.players.clamp(1, MAX_PLAYERS)
+    }
+
+    /// New velocity after steering towards `direction` for `delta` seconds, with the top
+    /// speed multiplied by `speed_factor`.
+    pub fn steer(&self, velocity: Vec2, direction: Vec2, speed_factor: f32, delta: f32) -> Vec2 {
+        let max_speed = self.max_speed * speed_factor;
+        match self.handling {
+            HandlingMode::Arcade => direction * max_speed,
+            HandlingMode::Inertial => {
+                let velocity = velocity + direction * self.acceleration * speed_factor * delta;
+                (velocity * (-self.friction * delta).exp()).clamp_length_max(max_speed)
+            }
+        }
+    }
+}
+
+#[derive(Resource, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub audio: AudioSettings,
+    pub world: WorldSettings,
+    pub effects: EffectsSettings,
+    pub controls: ControlSettings,
+}
+
+impl Settings {
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(contents) => ron::from_str(&contents).unwrap_or_else(|err| {
+                warn!("Invalid settings file {}: {}", path.display(), err);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .expect("Settings should always serialize");
+        if let Err(err) = fs::write(path, contents) {
+            warn!("Unable to save settings to {}: {}", path.display(), err);
+        }
+    }
+}
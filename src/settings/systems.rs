@@ -0,0 +1,43 @@
+use super::{resources::*, SETTINGS_PATH, VOLUME_STEP};
+use bevy::prelude::*;
+
+pub fn toggle_mute(keyboard_input: Res<Input<KeyCode>>, mut settings: ResMut<Settings>) {
+    if keyboard_input.just_pressed(KeyCode::M) {
+        settings.audio.muted = !settings.audio.muted;
+    }
+}
+
+pub fn toggle_reduced_motion(keyboard_input: Res<Input<KeyCode>>, mut settings: ResMut<Settings>) {
+    if keyboard_input.just_pressed(KeyCode::V) {
+        settings.effects.reduced_motion = !settings.effects.reduced_motion;
+    }
+}
+
+pub fn toggle_handling(keyboard_input: Res<Input<KeyCode>>, mut settings: ResMut<Settings>) {
+    if keyboard_input.just_pressed(KeyCode::H) {
+        settings.controls.handling = match settings.controls.handling {
+            HandlingMode::Arcade => HandlingMode::Inertial,
+            HandlingMode::Inertial => HandlingMode::Arcade,
+        };
+    }
+}
+
+pub fn adjust_master_volume(keyboard_input: Res<Input<KeyCode>>, mut settings: ResMut<Settings>) {
+    let mut step = 0.0;
+    if keyboard_input.just_pressed(KeyCode::Minus) {
+        step -= VOLUME_STEP;
+    }
+    if keyboard_input.just_pressed(KeyCode::Equals) {
+        step += VOLUME_STEP;
+    }
+
+    if step != 0.0 {
+        settings.audio.master_volume = (settings.audio.master_volume + step).clamp(0.0, 1.0);
+    }
+}
+
+pub fn save_settings(settings: Res<Settings>) {
+    if settings.is_changed() && !settings.is_added() {
+        settings.save(SETTINGS_PATH);
+    }
+}
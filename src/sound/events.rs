@@ -0,0 +1,7 @@
+use super::resources::SoundEffect;
+use bevy::prelude::*;
+
+pub struct PlaySoundEffect {
+    pub effect: SoundEffect,
+    pub position: Option<Vec3>,
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+pub mod events;
+pub mod resources;
+mod systems;
+
+use events::*;
+use resources::*;
+use systems::*;
+
+pub const MAX_SIMULTANEOUS_VOICES: usize = 8;
+pub const PITCH_VARIATION: f32 = 0.1;
+pub const VOLUME_VARIATION: f32 = 0.2;
+pub const EAR_GAP: f32 = 1.0;
+pub const ATTENUATION_DISTANCE: f32 = 800.0;
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlaySoundEffect>()
+            .init_resource::<SoundManager>()
+            .add_system(tick_sound_manager)
+            .add_system(play_sound_effects.after(tick_sound_manager))
+            .add_system(apply_effects_volume);
+    }
+}
@@ -0,0 +1,133 @@
+use bevy::{
+    audio::{Decodable, Source},
+    prelude::*,
+    utils::HashMap,
+};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SoundEffect {
+    Bounce,
+    Pickup,
+    Explosion,
+    Shot,
+}
+
+impl SoundEffect {
+    pub const ALL: [SoundEffect; 4] = [
+        SoundEffect::Bounce,
+        SoundEffect::Pickup,
+        SoundEffect::Explosion,
+        SoundEffect::Shot,
+    ];
+
+    /// Shortest time allowed between two plays of this effect.
+    pub fn min_interval(&self) -> f32 {
+        match self {
+            SoundEffect::Bounce => 0.12,
+            SoundEffect::Pickup => 0.06,
+            SoundEffect::Explosion => 0.0,
+            SoundEffect::Shot => 0.05,
+        }
+    }
+
+    /// Number of instances of this effect allowed to overlap.
+    pub fn max_voices(&self) -> usize {
+        match self {
+            SoundEffect::Bounce => 3,
+            SoundEffect::Pickup => 4,
+            SoundEffect::Explosion => 1,
+            SoundEffect::Shot => 3,
+        }
+    }
+}
+
+/// A playing sound. Bevy 0.10 sinks can't report when they run dry, so a voice is retired
+/// once its clip has had time to play through at the speed it was started with.
+pub struct Voice {
+    pub effect: SoundEffect,
+    pub sink: Handle<SpatialAudioSink>,
+    pub volume: f32,
+    pub timer: Timer,
+}
+
+#[derive(Resource)]
+pub struct SoundManager {
+    pub cooldowns: HashMap<SoundEffect, Timer>,
+    pub voices: Vec<Voice>,
+    /// Length in seconds of each clip played so far, measured by decoding it once.
+    pub clip_lengths: HashMap<Handle<AudioSource>, f32>,
+}
+
+impl Default for SoundManager {
+    fn default() -> Self {
+        let cooldowns = SoundEffect::ALL
+            .iter()
+            .map(|effect| {
+                let mut timer = Timer::from_seconds(effect.min_interval(), TimerMode::Once);
+                timer.tick(Duration::from_secs_f32(effect.min_interval()));
+                (*effect, timer)
+            })
+            .collect();
+
+        Self {
+            cooldowns,
+            voices: Vec::new(),
+            clip_lengths: HashMap::default(),
+        }
+    }
+}
+
+impl SoundManager {
+    pub fn active_voices(&self, effect: SoundEffect) -> usize {
+        self.voices.iter().filter(|v| v.effect == effect).count()
+    }
+
+    pub fn can_play(&self, effect: SoundEffect, max_voices: usize) -> bool {
+        let ready = self
+            .cooldowns
+            .get(&effect)
+            .is_none_or(|cooldown| cooldown.finished());
+
+        ready && self.voices.len() < max_voices && self.active_voices(effect) < effect.max_voices()
+    }
+
+    pub fn clip_length(
+        &mut self,
+        clip: &Handle<AudioSource>,
+        audio_sources: &Assets<AudioSource>,
+    ) -> Option<f32> {
+        if let Some(length) = self.clip_lengths.get(clip) {
+            return Some(*length);
+        }
+
+        let decoder = audio_sources.get(clip)?.decoder();
+        let length = match decoder.total_duration() {
+            Some(duration) => duration.as_secs_f32(),
+            None => {
+                let rate = decoder.channels().max(1) as f32 * decoder.sample_rate().max(1) as f32;
+                decoder.count() as f32 / rate
+            }
+        };
+        self.clip_lengths.insert(clip.clone(), length);
+        Some(length)
+    }
+
+    pub fn register(
+        &mut self,
+        effect: SoundEffect,
+        sink: Handle<SpatialAudioSink>,
+        volume: f32,
+        duration: f32,
+    ) {
+        if let Some(cooldown) = self.cooldowns.get_mut(&effect) {
+            cooldown.reset();
+        }
+        self.voices.push(Voice {
+            effect,
+            sink,
+            volume,
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        });
+    }
+}
@@ -0,0 +1,112 @@
+use super::{
+    events::*, resources::*, ATTENUATION_DISTANCE, EAR_GAP, MAX_SIMULTANEOUS_VOICES,
+    PITCH_VARIATION, VOLUME_VARIATION,
+};
+use crate::{
+    game::components::MainCamera, loading::resources::GameAssets, settings::resources::Settings,
+};
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::f32::consts::PI;
+
+/// Maps a world position to an emitter between the ears and a distance attenuation.
+///
+/// The emitter is kept within the ears so rodio's own distance falloff never applies, and the
+/// listener faces away from the screen because rodio 0.17 boosts the channel of the far ear.
+fn spatialise(
+    position: Option<Vec3>,
+    listener: Option<(&GlobalTransform, &OrthographicProjection)>,
+) -> (Transform, Vec3, f32) {
+    let listener_transform = Transform::from_rotation(Quat::from_rotation_y(PI));
+
+    let (Some(position), Some((camera_transform, projection))) = (position, listener) else {
+        return (listener_transform, Vec3::ZERO, 1.0);
+    };
+
+    let offset = (position - camera_transform.translation()).truncate();
+    let half_width = (projection.area.width() * 0.5).max(1.0);
+    let pan = (offset.x / half_width).clamp(-1.0, 1.0);
+    let attenuation = 1.0 / (1.0 + (offset.length() / ATTENUATION_DISTANCE).powi(2));
+
+    (
+        listener_transform,
+        Vec3::new(pan * EAR_GAP * 0.5, 0.0, 0.0),
+        attenuation,
+    )
+}
+
+pub fn tick_sound_manager(mut sound_manager: ResMut<SoundManager>, time: Res<Time>) {
+    for cooldown in sound_manager.cooldowns.values_mut() {
+        cooldown.tick(time.delta());
+    }
+    for voice in sound_manager.voices.iter_mut() {
+        voice.timer.tick(time.delta());
+    }
+    sound_manager.voices.retain(|voice| !voice.timer.finished());
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn play_sound_effects(
+    mut play_sound_event_reader: EventReader<PlaySoundEffect>,
+    mut sound_manager: ResMut<SoundManager>,
+    audio: Res<Audio>,
+    audio_sinks: Res<Assets<SpatialAudioSink>>,
+    audio_sources: Res<Assets<AudioSource>>,
+    camera_query: Query<(&GlobalTransform, &OrthographicProjection), With<MainCamera>>,
+    game_assets: Res<GameAssets>,
+    settings: Res<Settings>,
+) {
+    let mut rng = thread_rng();
+    let level = settings.audio.effects_level();
+    let listener = camera_query.get_single().ok();
+
+    for PlaySoundEffect { effect, position } in play_sound_event_reader.iter() {
+        if !sound_manager.can_play(*effect, MAX_SIMULTANEOUS_VOICES) {
+            continue;
+        }
+
+        let Some(clip) = game_assets.sound_effect(*effect).choose(&mut rng) else {
+            continue;
+        };
+        let Some(length) = sound_manager.clip_length(clip, &audio_sources) else {
+            continue;
+        };
+        let (listener_transform, emitter, attenuation) = spatialise(*position, listener);
+        let volume = (1.0 - rng.gen_range(0.0..=VOLUME_VARIATION)) * attenuation;
+        let speed = 1.0 + rng.gen_range(-PITCH_VARIATION..=PITCH_VARIATION);
+        let playback = PlaybackSettings::ONCE
+            .with_speed(speed)
+            .with_volume(volume * level);
+
+        let sink = audio.play_spatial_with_settings(
+            clip.clone(),
+            playback,
+            listener_transform,
+            EAR_GAP,
+            emitter,
+        );
+        sound_manager.register(
+            *effect,
+            audio_sinks.get_handle(sink),
+            volume,
+            length / speed,
+        );
+    }
+}
+
+pub fn apply_effects_volume(
+    sound_manager: Res<SoundManager>,
+    audio_sinks: Res<Assets<SpatialAudioSink>>,
+    settings: Res<Settings>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    let level = settings.audio.effects_level();
+    for voice in sound_manager.voices.iter() {
+        if let Some(sink) = audio_sinks.get(&voice.sink) {
+            sink.set_volume(voice.volume * level);
+        }
+    }
+}
@@ -1,4 +1,52 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StarTier {
+    #[default]
+    Common,
+    Rare,
+    Golden,
+}
+
+impl StarTier {
+    pub const ALL: [StarTier; 3] = [StarTier::Common, StarTier::Rare, StarTier::Golden];
+
+    pub fn points(&self) -> u32 {
+        match self {
+            StarTier::Common => 1,
+            StarTier::Rare => 5,
+            StarTier::Golden => 20,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            StarTier::Common => Color::rgb(1.0, 0.85, 0.2),
+            StarTier::Rare => Color::rgb(0.2, 0.75, 1.0),
+            StarTier::Golden => Color::rgb(1.0, 0.55, 0.1),
+        }
+    }
+
+    /// Relative chance of this tier being chosen for a star spawned over time.
+    pub fn weight(&self) -> u32 {
+        match self {
+            StarTier::Common => 80,
+            StarTier::Rare => 15,
+            StarTier::Golden => 5,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Star {
+    pub tier: StarTier,
+}
+
+#[derive(Component)]
+pub struct StarMetricsText {}
 
 #[derive(Component)]
-pub struct Star {}
+pub struct StarLifetime {
+    pub timer: Timer,
+}
@@ -0,0 +1,8 @@
+use super::components::StarTier;
+use bevy::prelude::*;
+
+pub struct StarCollected {
+    pub tier: StarTier,
+    pub points: u32,
+    pub position: Vec3,
+}
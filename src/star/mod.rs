@@ -1,23 +1,65 @@
 use bevy::prelude::*;
 
 pub mod components;
-mod resources;
+pub mod events;
+pub mod resources;
 mod systems;
 
+use crate::{
+    animation::components::{AnimationClip, AnimationMode},
+    game::states::AppState,
+    level::resources::Level,
+};
+use events::*;
 use resources::*;
 use systems::*;
 
-pub const INITIAL_NUMBER_OF_STARS: usize = 10;
 pub const STAR_SIZE: f32 = 30.0;
 pub const STAR_SPAWN_INTERVAL: f32 = 0.1;
+pub const MAX_NUMBER_OF_STARS: usize = 200;
+pub const STAR_SPAWN_ATTEMPTS: usize = 16;
+pub const STAR_LIFETIME: f32 = 8.0;
+pub const STAR_FADE_DURATION: f32 = 1.5;
+pub const STAR_TWINKLE: AnimationClip = AnimationClip {
+    frames: 8,
+    fps: 10.0,
+    mode: AnimationMode::Loop,
+};
+pub const STAR_EXPIRING: AnimationClip = AnimationClip {
+    frames: 8,
+    fps: 30.0,
+    mode: AnimationMode::Loop,
+};
 
 pub struct StarPlugin;
 
 impl Plugin for StarPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<StarSpawnTimer>()
-            .add_startup_system(spawn_stars)
-            .add_system(tick_star_spawn_timer)
-            .add_system(spawn_stars_over_time);
+            .init_resource::<StarPool>()
+            .init_resource::<StarMetrics>()
+            .add_event::<StarCollected>()
+            .add_system(spawn_stars.in_schedule(OnEnter(AppState::Game)))
+            .add_system(reset_star_spawn_timer.in_schedule(OnEnter(AppState::Game)))
+            .add_systems((despawn_stars, despawn_star_metrics).in_schedule(OnExit(AppState::Game)))
+            .add_system(spawn_stars.in_schedule(OnEnter(AppState::Editor)))
+            .add_system(despawn_stars.in_schedule(OnExit(AppState::Editor)))
+            .add_systems(
+                (despawn_stars, spawn_stars)
+                    .chain()
+                    .distributive_run_if(resource_changed::<Level>())
+                    .in_set(OnUpdate(AppState::Editor)),
+            )
+            .add_systems(
+                (
+                    tick_star_spawn_timer,
+                    spawn_stars_over_time,
+                    age_stars,
+                    update_star_metrics,
+                    toggle_star_metrics,
+                    update_star_metrics_text,
+                )
+                    .in_set(OnUpdate(AppState::Game)),
+            );
     }
 }
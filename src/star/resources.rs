@@ -1,4 +1,5 @@
-use super::STAR_SPAWN_INTERVAL;
+use super::{components::*, STAR_SPAWN_INTERVAL};
+use crate::animation::components::Animation;
 use bevy::prelude::*;
 
 #[derive(Resource)]
@@ -11,3 +12,40 @@ impl Default for StarSpawnTimer {
         ))
     }
 }
+
+/// Star entities that have been collected or expired, kept hidden for reuse.
+#[derive(Resource, Default)]
+pub struct StarPool {
+    pub free: Vec<Entity>,
+}
+
+impl StarPool {
+    pub fn release(&mut self, commands: &mut Commands, entity: Entity) {
+        if self.free.contains(&entity) {
+            return;
+        }
+
+        commands
+            .entity(entity)
+            .remove::<(Star, StarLifetime, Animation)>()
+            .insert(Visibility::Hidden)
+            .despawn_descendants();
+        self.free.push(entity);
+    }
+
+    /// Takes a pooled entity whose release has already been applied. Stars released this
+    /// frame still carry their `Star` component and are skipped until the next one.
+    pub fn acquire(&mut self, star_query: &Query<(), With<Star>>) -> Option<Entity> {
+        let index = self
+            .free
+            .iter()
+            .position(|entity| !star_query.contains(*entity))?;
+        Some(self.free.swap_remove(index))
+    }
+}
+
+#[derive(Resource, Default, Debug, PartialEq)]
+pub struct StarMetrics {
+    pub live: usize,
+    pub pooled: usize,
+}
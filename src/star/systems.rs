@@ -1,58 +1,195 @@
-use super::{components::Star, resources::*, INITIAL_NUMBER_OF_STARS};
-use bevy::{prelude::*, window::PrimaryWindow};
+use super::{components::*, resources::*};
+use super::{STAR_EXPIRING, STAR_FADE_DURATION, STAR_SIZE, STAR_SPAWN_ATTEMPTS, STAR_TWINKLE};
+use crate::{
+    animation::components::Animation, arena::resources::Arena, game_time::resources::GameTime,
+    level::resources::Level, loading::resources::GameAssets,
+};
+use bevy::prelude::*;
 use rand::prelude::*;
+use std::time::Duration;
 
-pub fn spawn_stars(
-    mut commands: Commands,
-    query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-) {
-    let window = query.get_single().unwrap();
-    let width = window.width();
-    let height = window.height();
+fn free_position(arena: &Arena, level: &Level) -> Option<Vec2> {
+    (0..STAR_SPAWN_ATTEMPTS)
+        .map(|_| arena.random_position())
+        .find(|position| !level.is_blocked(*position, STAR_SIZE * 0.5))
+}
 
-    for _ in 0..INITIAL_NUMBER_OF_STARS {
+pub fn spawn_stars(mut commands: Commands, level: Res<Level>, game_assets: Res<GameAssets>) {
+    for position in level.stars.iter() {
         commands.spawn((
-            Star {},
-            SpriteBundle {
-                transform: Transform::from_xyz(
-                    random::<f32>() * width,
-                    random::<f32>() * height,
-                    -1.0,
-                ),
-                texture: asset_server.load("sprites/star.png"),
+            Star {
+                tier: StarTier::Common,
+            },
+            Animation::new(STAR_TWINKLE).starting_at(random()),
+            SpriteSheetBundle {
+                transform: Transform::from_translation(position.extend(-1.0)),
+                texture_atlas: game_assets.star_atlas(StarTier::Common).clone(),
                 ..default()
             },
         ));
     }
 }
 
-pub fn tick_star_spawn_timer(mut star_spawn_timer: ResMut<StarSpawnTimer>, time: Res<Time>) {
-    star_spawn_timer.0.tick(time.delta());
+pub fn despawn_stars(
+    mut commands: Commands,
+    query: Query<Entity, With<Star>>,
+    mut star_pool: ResMut<StarPool>,
+) {
+    for entity in query.iter().chain(star_pool.free.drain(..)) {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn reset_star_spawn_timer(mut star_spawn_timer: ResMut<StarSpawnTimer>, level: Res<Level>) {
+    star_spawn_timer.0 = Timer::new(
+        Duration::from_secs_f32(level.spawns.star_interval),
+        TimerMode::Repeating,
+    );
+}
+
+pub fn tick_star_spawn_timer(
+    mut star_spawn_timer: ResMut<StarSpawnTimer>,
+    game_time: Res<GameTime>,
+) {
+    star_spawn_timer.0.tick(game_time.delta());
 }
 
 pub fn spawn_stars_over_time(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    arena: Res<Arena>,
+    level: Res<Level>,
+    game_assets: Res<GameAssets>,
     star_spawn_timer: Res<StarSpawnTimer>,
+    mut star_pool: ResMut<StarPool>,
+    star_query: Query<(), With<Star>>,
 ) {
-    if star_spawn_timer.0.finished() {
-        let window = window_query.get_single().unwrap();
-        let width = window.width();
-        let height = window.height();
+    if star_spawn_timer.0.finished() && star_query.iter().count() < level.spawns.max_stars {
+        let Some(position) = free_position(&arena, &level) else {
+            return;
+        };
 
-        commands.spawn((
-            Star {},
-            SpriteBundle {
-                transform: Transform::from_xyz(
-                    random::<f32>() * width,
-                    random::<f32>() * height,
-                    -1.0,
-                ),
-                texture: asset_server.load("sprites/star.png"),
+        let tier = *StarTier::ALL
+            .choose_weighted(&mut thread_rng(), StarTier::weight)
+            .unwrap_or(&StarTier::Common);
+
+        let bundle = (
+            Star { tier },
+            Animation::new(STAR_TWINKLE).starting_at(random()),
+            SpriteSheetBundle {
+                transform: Transform::from_translation(position.extend(-1.0)),
+                texture_atlas: game_assets.star_atlas(tier).clone(),
                 ..default()
             },
+        );
+        let mut star = match star_pool.acquire(&star_query) {
+            Some(entity) => {
+                let mut star = commands.entity(entity);
+                star.insert(bundle);
+                star
+            }
+            None => commands.spawn(bundle),
+        };
+        if let Some(lifetime) = level.spawns.star_lifetime {
+            star.insert(StarLifetime {
+                timer: Timer::from_seconds(lifetime, TimerMode::Once),
+            });
+        }
+    }
+}
+
+pub fn age_stars(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut StarLifetime,
+        &mut Animation,
+        &mut TextureAtlasSprite,
+    )>,
+    mut star_pool: ResMut<StarPool>,
+    game_time: Res<GameTime>,
+) {
+    for (entity, mut lifetime, mut animation, mut sprite) in query.iter_mut() {
+        lifetime.timer.tick(game_time.delta());
+        if lifetime.timer.finished() {
+            star_pool.release(&mut commands, entity);
+            continue;
+        }
+
+        let remaining = lifetime.timer.remaining_secs();
+        if remaining < STAR_FADE_DURATION {
+            animation.play(STAR_EXPIRING);
+        }
+        sprite
+            .color
+            .set_a((remaining / STAR_FADE_DURATION).min(1.0));
+    }
+}
+
+pub fn update_star_metrics(
+    mut star_metrics: ResMut<StarMetrics>,
+    star_query: Query<(), With<Star>>,
+    star_pool: Res<StarPool>,
+) {
+    let metrics = StarMetrics {
+        live: star_query.iter().count(),
+        pooled: star_pool.free.len(),
+    };
+    if *star_metrics != metrics {
+        *star_metrics = metrics;
+    }
+}
+
+pub fn toggle_star_metrics(
+    mut commands: Commands,
+    keyboard_input: Res<Input<KeyCode>>,
+    query: Query<Entity, With<StarMetricsText>>,
+    game_assets: Res<GameAssets>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F3) {
+        return;
+    }
+
+    if query.is_empty() {
+        commands.spawn((
+            StarMetricsText {},
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font: game_assets.font.clone(),
+                    font_size: 18.0,
+                    color: Color::WHITE,
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Px(10.0),
+                    bottom: Val::Px(10.0),
+                    ..default()
+                },
+                ..default()
+            }),
         ));
     }
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn update_star_metrics_text(
+    mut query: Query<&mut Text, With<StarMetricsText>>,
+    star_metrics: Res<StarMetrics>,
+) {
+    for mut text in query.iter_mut() {
+        text.sections[0].value = format!(
+            "Stars: {} live, {} pooled",
+            star_metrics.live, star_metrics.pooled
+        );
+    }
+}
+
+pub fn despawn_star_metrics(mut commands: Commands, query: Query<Entity, With<StarMetricsText>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
 }
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct StarfieldLayer {
+    pub parallax: f32,
+}
+
+/// Decorative background dot, deliberately unrelated to the `Star` pickup.
+#[derive(Component)]
+pub struct StarfieldDot {
+    pub origin: Vec2,
+}
@@ -0,0 +1,50 @@
+use bevy::{prelude::*, transform::TransformSystem};
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+use crate::camera_effects::CameraShakeSet;
+use resources::*;
+use systems::*;
+
+pub const STARFIELD_SEED: u64 = 0x5EED;
+pub const STARFIELD_TILE_SIZE: f32 = 2560.0;
+pub const STARFIELD_Z: f32 = -9.0;
+pub const STARFIELD_LAYERS: [StarfieldLayerSettings; 3] = [
+    StarfieldLayerSettings {
+        parallax: 0.1,
+        count: 600,
+        size: 1.5,
+        brightness: 0.35,
+    },
+    StarfieldLayerSettings {
+        parallax: 0.3,
+        count: 300,
+        size: 2.0,
+        brightness: 0.55,
+    },
+    StarfieldLayerSettings {
+        parallax: 0.6,
+        count: 120,
+        size: 3.0,
+        brightness: 0.8,
+    },
+];
+
+pub struct StarfieldPlugin;
+
+impl Plugin for StarfieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Starfield>()
+            .add_startup_system(spawn_starfield)
+            .add_system(regenerate_starfield)
+            // Wherever the camera was moved from this frame, follow it before it shakes.
+            .add_system(
+                scroll_starfield
+                    .in_base_set(CoreSet::PostUpdate)
+                    .before(CameraShakeSet)
+                    .before(TransformSystem::TransformPropagate),
+            );
+    }
+}
@@ -0,0 +1,22 @@
+use super::STARFIELD_SEED;
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct Starfield {
+    pub seed: u64,
+}
+
+impl Default for Starfield {
+    fn default() -> Self {
+        Self {
+            seed: STARFIELD_SEED,
+        }
+    }
+}
+
+pub struct StarfieldLayerSettings {
+    pub parallax: f32,
+    pub count: usize,
+    pub size: f32,
+    pub brightness: f32,
+}
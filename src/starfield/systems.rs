@@ -0,0 +1,98 @@
+use super::{components::*, resources::*, STARFIELD_LAYERS, STARFIELD_TILE_SIZE, STARFIELD_Z};
+use crate::game::components::MainCamera;
+use bevy::prelude::*;
+use rand::{prelude::*, rngs::StdRng};
+
+fn generate_starfield(commands: &mut Commands, seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for (index, layer) in STARFIELD_LAYERS.iter().enumerate() {
+        commands
+            .spawn((
+                StarfieldLayer {
+                    parallax: layer.parallax,
+                },
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    0.0,
+                    0.0,
+                    STARFIELD_Z + index as f32 * 0.1,
+                )),
+            ))
+            .with_children(|parent| {
+                for _ in 0..layer.count {
+                    let brightness = layer.brightness * rng.gen_range(0.6..=1.0);
+                    let tint: f32 = rng.gen_range(-0.15..=0.15);
+                    parent.spawn((
+                        StarfieldDot {
+                            origin: Vec2::new(rng.gen(), rng.gen()) * STARFIELD_TILE_SIZE,
+                        },
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::rgba(
+                                    1.0 - tint.max(0.0),
+                                    1.0,
+                                    1.0 + tint.min(0.0),
+                                    brightness,
+                                ),
+                                custom_size: Some(Vec2::splat(
+                                    layer.size * rng.gen_range(0.7..=1.3),
+                                )),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                    ));
+                }
+            });
+    }
+}
+
+pub fn spawn_starfield(mut commands: Commands, starfield: Res<Starfield>) {
+    generate_starfield(&mut commands, starfield.seed);
+}
+
+pub fn regenerate_starfield(
+    mut commands: Commands,
+    layer_query: Query<Entity, With<StarfieldLayer>>,
+    starfield: Res<Starfield>,
+) {
+    if !starfield.is_changed() || starfield.is_added() {
+        return;
+    }
+
+    for entity in layer_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    generate_starfield(&mut commands, starfield.seed);
+}
+
+/// Keeps each layer centred on the camera and slides its dots by the layer's parallax,
+/// wrapping them within a tile so the field never runs out.
+pub fn scroll_starfield(
+    camera_query: Query<&Transform, (With<MainCamera>, Without<StarfieldLayer>)>,
+    mut layer_query: Query<(&StarfieldLayer, &mut Transform), Without<StarfieldDot>>,
+    mut dot_query: Query<(&StarfieldDot, &Parent, &mut Transform), Without<StarfieldLayer>>,
+) {
+    let Ok(camera_transform) = camera_query.get_single() else {
+        return;
+    };
+    let camera = camera_transform.translation.truncate();
+
+    for (_, mut transform) in layer_query.iter_mut() {
+        transform.translation.x = camera.x;
+        transform.translation.y = camera.y;
+    }
+
+    for (dot, parent, mut transform) in dot_query.iter_mut() {
+        let Ok((layer, _)) = layer_query.get(parent.get()) else {
+            continue;
+        };
+        let shifted = dot.origin - camera * layer.parallax;
+        let local = Vec2::new(
+            shifted.x.rem_euclid(STARFIELD_TILE_SIZE),
+            shifted.y.rem_euclid(STARFIELD_TILE_SIZE),
+        ) - Vec2::splat(STARFIELD_TILE_SIZE * 0.5);
+        transform.translation.x = local.x;
+        transform.translation.y = local.y;
+    }
+}